@@ -10,7 +10,7 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, Address, Env, Symbol, Vec,
+    contract, contractimpl, contracttype, Address, BytesN, Env, Symbol, Vec,
 };
 
 /// Credit score tiers
@@ -118,6 +118,7 @@ pub enum DataKey {
     ScoreDistribution(CreditTier),
     AuthorizedInquirer(Address),
     InquiryCount(Address),
+    AuthorizedRecorder(Address),
 }
 
 /// Constants for scoring
@@ -125,6 +126,8 @@ const MIN_DAYS_FOR_SCORE: u64 = 90; // 90 days minimum for credit score
 const SECONDS_PER_DAY: u64 = 86400;
 const MAX_SCORE: u32 = 850;
 const MIN_SCORE: u32 = 300;
+/// Distinct stations tracked per user (diversity factor saturates well before this)
+const MAX_TRACKED_STATIONS: u32 = 50;
 
 /// Weight distribution for score calculation (must sum to 100)
 const WEIGHT_AGE: u32 = 20;
@@ -149,15 +152,61 @@ impl CreditScore {
         env.storage().instance().set(&DataKey::AverageScore, &0u32);
     }
 
+    /// Authorize a contract (e.g. voucher redemption) to record transactions
+    pub fn authorize_recorder(env: Env, admin: Address, recorder: Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic!("Unauthorized");
+        }
+
+        env.storage().persistent().set(&DataKey::AuthorizedRecorder(recorder.clone()), &true);
+
+        env.events().publish(
+            (Symbol::new(&env, "recorder_authorized"), recorder),
+            admin,
+        );
+    }
+
+    /// Revoke a recorder's authorization
+    pub fn revoke_recorder(env: Env, admin: Address, recorder: Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic!("Unauthorized");
+        }
+
+        env.storage().persistent().remove(&DataKey::AuthorizedRecorder(recorder.clone()));
+
+        env.events().publish(
+            (Symbol::new(&env, "recorder_revoked"), recorder),
+            admin,
+        );
+    }
+
+    /// Check if an address is an authorized recorder
+    pub fn is_recorder(env: Env, recorder: Address) -> bool {
+        env.storage().persistent()
+            .get(&DataKey::AuthorizedRecorder(recorder))
+            .unwrap_or(false)
+    }
+
     /// Record a fuel transaction for credit scoring
-    /// This should be called by the voucher redemption contract
+    /// This should be called by the voucher redemption contract, which must be
+    /// an authorized recorder
     pub fn record_transaction(
         env: Env,
+        recorder: Address,
         user: Address,
         amount: i128,
-        station_count: u32,
+        station_id: BytesN<32>,
         _timestamp: u64, // Using underscore to indicate intentionally unused
     ) {
+        recorder.require_auth();
+        if !Self::is_recorder(env.clone(), recorder) {
+            panic!("Recorder not authorized");
+        }
+
         let timestamp = env.ledger().timestamp();
         let day = timestamp / SECONDS_PER_DAY;
         let profile_key = DataKey::CreditProfile(user.clone());
@@ -211,7 +260,15 @@ impl CreditScore {
         }
 
         // Update unique stations
-        profile.unique_stations = station_count.max(profile.unique_stations);
+        let stations_key = DataKey::StationsVisited(user.clone());
+        let mut stations: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&stations_key)
+            .unwrap_or(Vec::new(&env));
+        if !stations.contains(&station_id) && stations.len() < MAX_TRACKED_STATIONS {
+            stations.push_back(station_id);
+            env.storage().persistent().set(&stations_key, &stations);
+        }
+        profile.unique_stations = stations.len();
         profile.last_transaction_at = timestamp;
 
         // Store daily activity
@@ -229,7 +286,7 @@ impl CreditScore {
 
         daily_activity.transaction_count += 1;
         daily_activity.total_amount += amount;
-        daily_activity.stations_visited = station_count;
+        daily_activity.stations_visited = profile.unique_stations;
         env.storage().persistent().set(&daily_key, &daily_activity);
 
         // Calculate and update score
@@ -2,7 +2,9 @@
 
 use soroban_sdk::{Address, Env};
 
-use crate::storage_types::{DataKey, INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
+use crate::storage_types::{
+    DataKey, PendingAdmin, INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD,
+};
 
 /// Check if there is an admin set for the contract
 pub fn has_admin(env: &Env) -> bool {
@@ -22,6 +24,21 @@ pub fn write_admin(env: &Env, id: &Address) {
     env.storage().instance().set(&key, id);
 }
 
+/// Read the pending admin handover, if any
+pub fn read_pending_admin(env: &Env) -> Option<PendingAdmin> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+/// Write the pending admin handover
+pub fn write_pending_admin(env: &Env, pending: &PendingAdmin) {
+    env.storage().instance().set(&DataKey::PendingAdmin, pending);
+}
+
+/// Clear the pending admin handover
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
 /// Extend the TTL of the instance storage
 pub fn extend_instance_ttl(env: &Env) {
     env.storage()
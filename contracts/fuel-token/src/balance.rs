@@ -2,6 +2,7 @@
 
 use soroban_sdk::{Address, Env};
 
+use crate::checkpoint::{read_total_supply, write_balance_checkpoint, write_total_supply};
 use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
 
 /// Read the balance of an address
@@ -24,6 +25,7 @@ fn write_balance(env: &Env, addr: &Address, amount: i128) {
     env.storage()
         .persistent()
         .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    write_balance_checkpoint(env, addr, amount);
 }
 
 /// Increase the balance of an address (for minting or receiving transfers)
@@ -41,6 +43,18 @@ pub fn spend_balance(env: &Env, addr: &Address, amount: i128) {
     write_balance(env, addr, balance - amount);
 }
 
+/// Create new tokens in an address, increasing the total supply
+pub fn mint_balance(env: &Env, addr: &Address, amount: i128) {
+    receive_balance(env, addr, amount);
+    write_total_supply(env, read_total_supply(env) + amount);
+}
+
+/// Destroy tokens held by an address, decreasing the total supply
+pub fn burn_balance(env: &Env, addr: &Address, amount: i128) {
+    spend_balance(env, addr, amount);
+    write_total_supply(env, read_total_supply(env) - amount);
+}
+
 /// Check if an address has sufficient balance
 pub fn has_sufficient_balance(env: &Env, addr: &Address, amount: i128) -> bool {
     read_balance(env, addr) >= amount
@@ -0,0 +1,139 @@
+//! Balance and total supply checkpoints for historical queries
+//!
+//! Every balance change records `(ledger, value)`. Histories are compacted once
+//! they exceed `MAX_CHECKPOINTS`: older checkpoints are thinned to the last one
+//! per day, then the current day if needed, and only once a whole
+//! `MAX_CHECKPOINTS` days of closing values remain are the oldest days dropped.
+//! A thinned day keeps its closing value exactly; earlier ledgers within it
+//! resolve to the previous day's close.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage_types::{
+    Checkpoint, CheckpointHistory, DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+    DAY_LEDGERS, MAX_CHECKPOINTS,
+};
+
+fn read_history(env: &Env, key: &DataKey) -> CheckpointHistory {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or(CheckpointHistory {
+            retained_from: 0,
+            checkpoints: Vec::new(env),
+        })
+}
+
+fn write_history(env: &Env, key: &DataKey, history: &CheckpointHistory) {
+    env.storage().persistent().set(key, history);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+/// Keep the last checkpoint of each day, and every checkpoint of days from
+/// `keep_from_day` onwards
+fn thin_to_daily(env: &Env, checkpoints: &Vec<Checkpoint>, keep_from_day: u32) -> Vec<Checkpoint> {
+    let mut thinned = Vec::new(env);
+    for i in 0..checkpoints.len() {
+        let checkpoint = checkpoints.get(i).unwrap();
+        let day = checkpoint.ledger / DAY_LEDGERS;
+        let is_last_of_day = match checkpoints.get(i + 1) {
+            Some(next) => next.ledger / DAY_LEDGERS != day,
+            None => true,
+        };
+        if day >= keep_from_day || is_last_of_day {
+            thinned.push_back(checkpoint);
+        }
+    }
+    thinned
+}
+
+/// Thin checkpoints from previous days to one per day, then the current day
+/// if that is not enough, then drop the oldest days until the history fits
+fn compact(env: &Env, history: &mut CheckpointHistory) {
+    let current_day = env.ledger().sequence() / DAY_LEDGERS;
+    let mut compacted = thin_to_daily(env, &history.checkpoints, current_day);
+    if compacted.len() > MAX_CHECKPOINTS {
+        compacted = thin_to_daily(env, &compacted, u32::MAX);
+    }
+
+    if compacted.len() > MAX_CHECKPOINTS {
+        while compacted.len() > MAX_CHECKPOINTS {
+            compacted.pop_front_unchecked();
+        }
+        // Ledgers before the first surviving checkpoint had the dropped values
+        history.retained_from = compacted.first_unchecked().ledger;
+    }
+    history.checkpoints = compacted;
+}
+
+fn push_checkpoint(env: &Env, key: &DataKey, value: i128) {
+    let ledger = env.ledger().sequence();
+    let mut history = read_history(env, key);
+    let len = history.checkpoints.len();
+    if len > 0 && history.checkpoints.get(len - 1).unwrap().ledger == ledger {
+        history.checkpoints.set(len - 1, Checkpoint { ledger, value });
+    } else {
+        history.checkpoints.push_back(Checkpoint { ledger, value });
+        if history.checkpoints.len() > MAX_CHECKPOINTS {
+            compact(env, &mut history);
+        }
+    }
+    write_history(env, key, &history);
+}
+
+/// Find the value at the end of `ledger` by binary search
+fn value_at(env: &Env, key: &DataKey, ledger: u32) -> i128 {
+    let history = read_history(env, key);
+    if ledger < history.retained_from {
+        panic!("Checkpoint history not retained for ledger");
+    }
+
+    let checkpoints = history.checkpoints;
+    let mut low = 0;
+    let mut high = checkpoints.len();
+    while low < high {
+        let mid = (low + high) / 2;
+        if checkpoints.get(mid).unwrap().ledger <= ledger {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    if low == 0 {
+        0
+    } else {
+        checkpoints.get(low - 1).unwrap().value
+    }
+}
+
+/// Record the new balance of an address
+pub fn write_balance_checkpoint(env: &Env, addr: &Address, balance: i128) {
+    push_checkpoint(env, &DataKey::Checkpoints(addr.clone()), balance);
+}
+
+/// Balance of an address at the end of a ledger
+pub fn read_balance_at(env: &Env, addr: &Address, ledger: u32) -> i128 {
+    value_at(env, &DataKey::Checkpoints(addr.clone()), ledger)
+}
+
+/// Read the current total supply
+pub fn read_total_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalSupply)
+        .unwrap_or(0)
+}
+
+/// Write the total supply and record a supply checkpoint
+pub fn write_total_supply(env: &Env, supply: i128) {
+    env.storage().instance().set(&DataKey::TotalSupply, &supply);
+    push_checkpoint(env, &DataKey::SupplyCheckpoints, supply);
+}
+
+/// Total supply at the end of a ledger
+pub fn read_total_supply_at(env: &Env, ledger: u32) -> i128 {
+    value_at(env, &DataKey::SupplyCheckpoints, ledger)
+}
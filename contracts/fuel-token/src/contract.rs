@@ -3,45 +3,88 @@
 //! SEP-41 compliant fungible token for the FuelAnchor fuel voucher system.
 //! Enables fleet operators to issue tokenized fuel credits to drivers.
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, token::TokenInterface, Address, BytesN, Env, String};
+use soroban_token_sdk::metadata::TokenMetadata;
 use soroban_token_sdk::TokenUtils;
 
-use crate::admin::{extend_instance_ttl, has_admin, read_admin, require_admin, write_admin};
+use crate::admin::{
+    extend_instance_ttl, has_admin, read_admin, read_pending_admin, remove_pending_admin,
+    require_admin, write_admin, write_pending_admin,
+};
+use crate::council::{
+    next_proposal_id, read_council, read_proposal, remove_council, require_council_member,
+    require_no_council, write_council, write_proposal,
+};
 use crate::allowance::{read_allowance, spend_allowance, write_allowance};
-use crate::balance::{read_balance, receive_balance, spend_balance};
+use crate::balance::{burn_balance, mint_balance, read_balance, receive_balance, spend_balance};
+use crate::checkpoint::{read_balance_at, read_total_supply, read_total_supply_at};
 use crate::metadata::{read_decimal, read_name, read_symbol, write_metadata};
-use crate::storage_types::TokenMetadata;
-
-/// Event topics for token operations
+use crate::pause::{is_paused, require_not_paused, write_paused};
+use crate::permit::{account_address, consume_nonce, read_nonce, verify_permit};
+use crate::redemption::{
+    add_pending, next_redemption_id, read_pending_ids, read_redemption, remove_pending,
+    write_redemption,
+};
+use crate::storage_types::{
+    Council, CouncilAction, CouncilProposal, PauseScope, PendingAdmin, PermitPayload,
+    RedemptionRequest, RedemptionStatus, ADMIN_HANDOVER_LEDGERS, COUNCIL_PROPOSAL_LIFETIME,
+    MAX_BATCH_SIZE,
+};
+
+/// Standard SEP-41 events, emitted through the token SDK so wallets and
+/// explorers recognize FUEL
 fn emit_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
-    let topics = (soroban_sdk::Symbol::new(env, "transfer"), from, to);
-    env.events().publish(topics, amount);
+    TokenUtils::new(env)
+        .events()
+        .transfer(from.clone(), to.clone(), amount);
 }
 
 fn emit_approval(env: &Env, from: &Address, spender: &Address, amount: i128, expiration: u32) {
-    let topics = (soroban_sdk::Symbol::new(env, "approve"), from, spender);
-    env.events().publish(topics, (amount, expiration));
+    TokenUtils::new(env)
+        .events()
+        .approve(from.clone(), spender.clone(), amount, expiration);
 }
 
 fn emit_mint(env: &Env, admin: &Address, to: &Address, amount: i128) {
-    let topics = (soroban_sdk::Symbol::new(env, "mint"), admin, to);
-    env.events().publish(topics, amount);
+    TokenUtils::new(env)
+        .events()
+        .mint(admin.clone(), to.clone(), amount);
 }
 
 fn emit_burn(env: &Env, from: &Address, amount: i128) {
-    let topics = (soroban_sdk::Symbol::new(env, "burn"), from);
-    env.events().publish(topics, amount);
+    TokenUtils::new(env).events().burn(from.clone(), amount);
 }
 
 fn emit_set_admin(env: &Env, old_admin: &Address, new_admin: &Address) {
-    let topics = (soroban_sdk::Symbol::new(env, "set_admin"), old_admin);
-    env.events().publish(topics, new_admin);
+    TokenUtils::new(env)
+        .events()
+        .set_admin(old_admin.clone(), new_admin.clone());
+}
+
+fn emit_clawback(env: &Env, admin: &Address, from: &Address, amount: i128) {
+    TokenUtils::new(env)
+        .events()
+        .clawback(admin.clone(), from.clone(), amount);
+}
+
+fn emit_redemption(env: &Env, action: &str, station: &Address, id: u64, amount: i128) {
+    let topics = (soroban_sdk::Symbol::new(env, action), station, id);
+    env.events().publish(topics, amount);
+}
+
+fn emit_batch_summary(env: &Env, action: &str, actor: &Address, count: u32, total: i128) {
+    let topics = (soroban_sdk::Symbol::new(env, action), actor);
+    env.events().publish(topics, (count, total));
 }
 
-/// Check nonces for replay protection
-fn check_nonce(env: &Env, id: &Address) -> i128 {
-    let key = crate::storage_types::DataKey::Nonce(id.clone());
-    env.storage().persistent().get(&key).unwrap_or(0)
+/// Validate batch input lengths
+fn check_batch(len: u32, amounts_len: u32) {
+    if len != amounts_len {
+        panic!("Recipients and amounts length mismatch");
+    }
+    if len > MAX_BATCH_SIZE {
+        panic!("Batch too large");
+    }
 }
 
 /// Validate and update amount
@@ -74,115 +117,260 @@ impl FuelToken {
         );
     }
 
-    // ==================== SEP-41 Token Interface ====================
+    // ==================== Historical Balances ====================
 
-    /// Get the allowance for a spender from an owner
-    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+    /// Get the balance of an address at the end of a ledger.
+    /// Older history is kept at one checkpoint per day.
+    pub fn balance_at(env: Env, id: Address, ledger: u32) -> i128 {
         extend_instance_ttl(&env);
-        read_allowance(&env, &from, &spender).amount
+        read_balance_at(&env, &id, ledger)
     }
 
-    /// Approve a spender to spend tokens on behalf of the caller
-    pub fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
-        from.require_auth();
-        check_non_negative_amount(amount);
+    /// Get the current total supply
+    pub fn total_supply(env: Env) -> i128 {
         extend_instance_ttl(&env);
-        write_allowance(&env, &from, &spender, amount, expiration_ledger);
-        emit_approval(&env, &from, &spender, amount, expiration_ledger);
+        read_total_supply(&env)
     }
 
-    /// Get the balance of an address
-    pub fn balance(env: Env, id: Address) -> i128 {
+    /// Get the total supply at the end of a ledger
+    pub fn total_supply_at(env: Env, ledger: u32) -> i128 {
         extend_instance_ttl(&env);
-        read_balance(&env, &id)
+        read_total_supply_at(&env, ledger)
     }
 
-    /// Transfer tokens from the caller to another address
-    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
-        from.require_auth();
+    // ==================== Permit Approvals ====================
+
+    /// Set an allowance from an ed25519-signed `PermitPayload`, so a relayer can
+    /// submit approvals for owners who cannot sign transactions themselves.
+    /// The owner is the Stellar account of `owner_pubkey`.
+    pub fn permit(
+        env: Env,
+        owner_pubkey: BytesN<32>,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        nonce: i128,
+        signature: BytesN<64>,
+    ) {
         check_non_negative_amount(amount);
         extend_instance_ttl(&env);
-        spend_balance(&env, &from, amount);
-        receive_balance(&env, &to, amount);
-        emit_transfer(&env, &from, &to, amount);
+
+        let payload = PermitPayload {
+            network_id: env.ledger().network_id(),
+            contract: env.current_contract_address(),
+            owner_pubkey: owner_pubkey.clone(),
+            spender: spender.clone(),
+            amount,
+            expiration_ledger,
+            nonce,
+        };
+        verify_permit(&env, &payload, &signature);
+
+        let owner = account_address(&env, &owner_pubkey);
+        consume_nonce(&env, &owner, nonce);
+        write_allowance(&env, &owner, &spender, amount, expiration_ledger);
+        emit_approval(&env, &owner, &spender, amount, expiration_ledger);
     }
 
-    /// Transfer tokens from one address to another using an allowance
-    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
-        spender.require_auth();
-        check_non_negative_amount(amount);
+    /// Get the next permit nonce for an owner
+    pub fn nonce(env: Env, owner: Address) -> i128 {
         extend_instance_ttl(&env);
-        spend_allowance(&env, &from, &spender, amount);
-        spend_balance(&env, &from, amount);
-        receive_balance(&env, &to, amount);
-        emit_transfer(&env, &from, &to, amount);
+        read_nonce(&env, &owner)
     }
 
-    /// Burn tokens from an address (requires authorization)
-    pub fn burn(env: Env, from: Address, amount: i128) {
-        from.require_auth();
+    // ==================== Admin Functions ====================
+
+    /// Mint new tokens (admin only)
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        require_not_paused(&env, PauseScope::Mint);
         check_non_negative_amount(amount);
+        require_no_council(&env);
+        let admin = read_admin(&env);
+        admin.require_auth();
         extend_instance_ttl(&env);
-        spend_balance(&env, &from, amount);
-        emit_burn(&env, &from, amount);
+        mint_balance(&env, &to, amount);
+        emit_mint(&env, &admin, &to, amount);
     }
 
-    /// Burn tokens from an address using an allowance
-    pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
-        spender.require_auth();
-        check_non_negative_amount(amount);
+    /// Propose a new admin (current admin only).
+    /// Kept for compatibility; the handover still has to be accepted via `accept_admin`.
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Self::propose_admin(env, new_admin);
+    }
+
+    /// Propose a new admin (current admin only).
+    /// The proposed admin must call `accept_admin` before the handover expires.
+    pub fn propose_admin(env: Env, new_admin: Address) {
         extend_instance_ttl(&env);
-        spend_allowance(&env, &from, &spender, amount);
-        spend_balance(&env, &from, amount);
-        emit_burn(&env, &from, amount);
+        require_no_council(&env);
+        let admin = read_admin(&env);
+        require_admin(&env, &admin);
+
+        let expiration_ledger = env.ledger().sequence() + ADMIN_HANDOVER_LEDGERS;
+        write_pending_admin(
+            &env,
+            &PendingAdmin {
+                new_admin: new_admin.clone(),
+                expiration_ledger,
+            },
+        );
+
+        let topics = (soroban_sdk::Symbol::new(&env, "admin_proposed"), admin);
+        env.events().publish(topics, (new_admin, expiration_ledger));
     }
 
-    /// Get the number of decimals for the token
-    pub fn decimals(env: Env) -> u32 {
-        read_decimal(&env)
+    /// Accept a pending admin handover (proposed admin only)
+    pub fn accept_admin(env: Env) {
+        extend_instance_ttl(&env);
+        require_no_council(&env);
+        let pending = read_pending_admin(&env).expect("No pending admin");
+        pending.new_admin.require_auth();
+        if env.ledger().sequence() > pending.expiration_ledger {
+            panic!("Admin handover expired");
+        }
+
+        let old_admin = read_admin(&env);
+        write_admin(&env, &pending.new_admin);
+        remove_pending_admin(&env);
+        emit_set_admin(&env, &old_admin, &pending.new_admin);
     }
 
-    /// Get the name of the token
-    pub fn name(env: Env) -> String {
-        read_name(&env)
+    /// Get the pending admin handover, if any
+    pub fn pending_admin(env: Env) -> Option<PendingAdmin> {
+        extend_instance_ttl(&env);
+        read_pending_admin(&env)
     }
 
-    /// Get the symbol of the token
-    pub fn symbol(env: Env) -> String {
-        read_symbol(&env)
+    /// Get the current admin address
+    pub fn admin(env: Env) -> Address {
+        extend_instance_ttl(&env);
+        read_admin(&env)
     }
 
-    // ==================== Admin Functions ====================
+    /// Pause a scope of operations (admin only).
+    /// Clawback stays available while paused.
+    pub fn pause(env: Env, scope: PauseScope) {
+        extend_instance_ttl(&env);
+        let admin = read_admin(&env);
+        require_admin(&env, &admin);
+        write_paused(&env, scope, true);
 
-    /// Mint new tokens (admin only)
-    pub fn mint(env: Env, to: Address, amount: i128) {
-        check_non_negative_amount(amount);
+        let topics = (soroban_sdk::Symbol::new(&env, "paused"), admin);
+        env.events().publish(topics, scope);
+    }
+
+    /// Unpause a scope of operations (admin only)
+    pub fn unpause(env: Env, scope: PauseScope) {
+        extend_instance_ttl(&env);
         let admin = read_admin(&env);
-        admin.require_auth();
+        require_admin(&env, &admin);
+        write_paused(&env, scope, false);
+
+        let topics = (soroban_sdk::Symbol::new(&env, "unpaused"), admin);
+        env.events().publish(topics, scope);
+    }
+
+    /// Check if a scope of operations is paused
+    pub fn is_paused(env: Env, scope: PauseScope) -> bool {
         extend_instance_ttl(&env);
-        receive_balance(&env, &to, amount);
-        emit_mint(&env, &admin, &to, amount);
+        is_paused(&env, scope)
     }
 
-    /// Set a new admin (current admin only)
-    pub fn set_admin(env: Env, new_admin: Address) {
+    // ==================== Admin Council ====================
+
+    /// Hand privileged operations over to an M-of-N council (admin only).
+    /// Once set, `mint`, `clawback` and admin changes must go through council proposals.
+    pub fn set_council(env: Env, members: soroban_sdk::Vec<Address>, threshold: u32) {
         extend_instance_ttl(&env);
-        let old_admin = read_admin(&env);
-        require_admin(&env, &old_admin);
-        write_admin(&env, &new_admin);
-        emit_set_admin(&env, &old_admin, &new_admin);
+        require_no_council(&env);
+        let admin = read_admin(&env);
+        require_admin(&env, &admin);
+        write_council(&env, &members, threshold);
+        // Admin changes go through the council from here on.
+        remove_pending_admin(&env);
+
+        let topics = (soroban_sdk::Symbol::new(&env, "council_set"), admin);
+        env.events().publish(topics, (members.len(), threshold));
     }
 
-    /// Get the current admin address
-    pub fn admin(env: Env) -> Address {
+    /// Get the admin council configuration
+    pub fn council(env: Env) -> Council {
         extend_instance_ttl(&env);
-        read_admin(&env)
+        read_council(&env)
+    }
+
+    /// Propose a privileged action (council member only).
+    /// The proposer's approval is recorded; the action executes once the threshold is met.
+    pub fn propose_council_action(env: Env, member: Address, action: CouncilAction) -> u64 {
+        extend_instance_ttl(&env);
+        let council = require_council_member(&env, &member);
+
+        let id = next_proposal_id(&env);
+        let mut proposal = CouncilProposal {
+            id,
+            action,
+            approvals: soroban_sdk::vec![&env, member.clone()],
+            executed: false,
+            created_at: env.ledger().timestamp(),
+            epoch: council.epoch,
+        };
+
+        let topics = (soroban_sdk::Symbol::new(&env, "council_proposed"), member, id);
+        env.events().publish(topics, proposal.action.clone());
+
+        if proposal.approvals.len() >= council.threshold {
+            Self::execute_council_action(&env, &mut proposal);
+        }
+        write_proposal(&env, &proposal);
+        id
+    }
+
+    /// Approve a pending council proposal (council member only).
+    /// Proposals lapse after `COUNCIL_PROPOSAL_LIFETIME` seconds, and when the
+    /// council that received them is replaced or dissolved.
+    /// Returns true if this approval caused the action to execute.
+    pub fn approve_council_action(env: Env, member: Address, id: u64) -> bool {
+        extend_instance_ttl(&env);
+        let council = require_council_member(&env, &member);
+
+        let mut proposal = read_proposal(&env, id);
+        if proposal.executed {
+            panic!("Proposal already executed");
+        }
+        if proposal.epoch != council.epoch {
+            panic!("Proposal made to a previous council");
+        }
+        if env.ledger().timestamp() > proposal.created_at + COUNCIL_PROPOSAL_LIFETIME {
+            panic!("Proposal expired");
+        }
+        if proposal.approvals.contains(&member) {
+            panic!("Proposal already approved by member");
+        }
+        proposal.approvals.push_back(member.clone());
+
+        let topics = (soroban_sdk::Symbol::new(&env, "council_approved"), member, id);
+        env.events().publish(topics, proposal.approvals.len());
+
+        let executed = proposal.approvals.len() >= council.threshold;
+        if executed {
+            Self::execute_council_action(&env, &mut proposal);
+        }
+        write_proposal(&env, &proposal);
+        executed
+    }
+
+    /// Get a council proposal by id
+    pub fn get_council_proposal(env: Env, id: u64) -> CouncilProposal {
+        extend_instance_ttl(&env);
+        read_proposal(&env, id)
     }
 
     // ==================== FuelAnchor Specific Functions ====================
 
     /// Batch mint tokens to multiple addresses (for fleet distribution)
     pub fn batch_mint(env: Env, recipients: soroban_sdk::Vec<Address>, amounts: soroban_sdk::Vec<i128>) {
+        require_not_paused(&env, PauseScope::Mint);
+        require_no_council(&env);
         let admin = read_admin(&env);
         admin.require_auth();
         extend_instance_ttl(&env);
@@ -195,7 +383,7 @@ impl FuelToken {
             let to = recipients.get(i).unwrap();
             let amount = amounts.get(i).unwrap();
             check_non_negative_amount(amount);
-            receive_balance(&env, &to, amount);
+            mint_balance(&env, &to, amount);
             emit_mint(&env, &admin, &to, amount);
         }
     }
@@ -203,12 +391,284 @@ impl FuelToken {
     /// Clawback tokens from an address (admin only, for fraud prevention)
     pub fn clawback(env: Env, from: Address, amount: i128) {
         check_non_negative_amount(amount);
+        require_no_council(&env);
+        let admin = read_admin(&env);
+        admin.require_auth();
+        extend_instance_ttl(&env);
+        burn_balance(&env, &from, amount);
+        emit_clawback(&env, &admin, &from, amount);
+    }
+
+    /// Transfer tokens from one address to many recipients (for fleet redistribution).
+    /// Either every transfer succeeds or the whole batch is reverted.
+    pub fn batch_transfer(
+        env: Env,
+        from: Address,
+        recipients: soroban_sdk::Vec<Address>,
+        amounts: soroban_sdk::Vec<i128>,
+    ) {
+        require_not_paused(&env, PauseScope::Transfer);
+        from.require_auth();
+        check_batch(recipients.len(), amounts.len());
+        extend_instance_ttl(&env);
+
+        let mut total: i128 = 0;
+        for i in 0..recipients.len() {
+            let to = recipients.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+            check_non_negative_amount(amount);
+            spend_balance(&env, &from, amount);
+            receive_balance(&env, &to, amount);
+            emit_transfer(&env, &from, &to, amount);
+            total += amount;
+        }
+
+        emit_batch_summary(&env, "batch_transfer", &from, recipients.len(), total);
+    }
+
+    /// Clawback tokens from many addresses (admin only, for fraud rings).
+    /// Either every clawback succeeds or the whole batch is reverted.
+    pub fn batch_clawback(env: Env, accounts: soroban_sdk::Vec<Address>, amounts: soroban_sdk::Vec<i128>) {
+        require_no_council(&env);
         let admin = read_admin(&env);
         admin.require_auth();
+        check_batch(accounts.len(), amounts.len());
+        extend_instance_ttl(&env);
+
+        let mut total: i128 = 0;
+        for i in 0..accounts.len() {
+            let from = accounts.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+            check_non_negative_amount(amount);
+            burn_balance(&env, &from, amount);
+            emit_clawback(&env, &admin, &from, amount);
+            total += amount;
+        }
+
+        emit_batch_summary(&env, "batch_clawback", &admin, accounts.len(), total);
+    }
+
+    // ==================== Station Redemption ====================
+
+    /// Request a fiat payout for FUEL held by a station.
+    /// The tokens are moved into escrow until the admin confirms or rejects the request.
+    pub fn request_redemption(
+        env: Env,
+        station: Address,
+        amount: i128,
+        payout_reference: String,
+    ) -> u64 {
+        require_not_paused(&env, PauseScope::Burn);
+        station.require_auth();
+        if amount <= 0 {
+            panic!("Redemption amount must be positive");
+        }
+        extend_instance_ttl(&env);
+
+        let escrow = env.current_contract_address();
+        spend_balance(&env, &station, amount);
+        receive_balance(&env, &escrow, amount);
+        emit_transfer(&env, &station, &escrow, amount);
+
+        let id = next_redemption_id(&env);
+        let request = RedemptionRequest {
+            id,
+            station: station.clone(),
+            amount,
+            payout_reference,
+            status: RedemptionStatus::Pending,
+            requested_at: env.ledger().timestamp(),
+            resolved_at: 0,
+        };
+        write_redemption(&env, &request);
+        add_pending(&env, &station, id);
+
+        emit_redemption(&env, "redemption_requested", &station, id, amount);
+        id
+    }
+
+    /// Confirm a redemption after the off-chain payout (admin only).
+    /// Burns the escrowed tokens.
+    pub fn confirm_redemption(env: Env, id: u64) {
+        require_not_paused(&env, PauseScope::Burn);
+        let admin = read_admin(&env);
+        admin.require_auth();
+        extend_instance_ttl(&env);
+
+        let mut request = read_redemption(&env, id);
+        if request.status != RedemptionStatus::Pending {
+            panic!("Redemption is not pending");
+        }
+
+        let escrow = env.current_contract_address();
+        burn_balance(&env, &escrow, request.amount);
+        emit_burn(&env, &escrow, request.amount);
+
+        request.status = RedemptionStatus::Confirmed;
+        request.resolved_at = env.ledger().timestamp();
+        write_redemption(&env, &request);
+        remove_pending(&env, &request.station, id);
+
+        emit_redemption(&env, "redemption_confirmed", &request.station, id, request.amount);
+    }
+
+    /// Reject a redemption (admin only), returning the escrowed tokens to the station
+    pub fn reject_redemption(env: Env, id: u64) {
+        let admin = read_admin(&env);
+        admin.require_auth();
+        extend_instance_ttl(&env);
+
+        let mut request = read_redemption(&env, id);
+        if request.status != RedemptionStatus::Pending {
+            panic!("Redemption is not pending");
+        }
+
+        let escrow = env.current_contract_address();
+        spend_balance(&env, &escrow, request.amount);
+        receive_balance(&env, &request.station, request.amount);
+        emit_transfer(&env, &escrow, &request.station, request.amount);
+
+        request.status = RedemptionStatus::Rejected;
+        request.resolved_at = env.ledger().timestamp();
+        write_redemption(&env, &request);
+        remove_pending(&env, &request.station, id);
+
+        emit_redemption(&env, "redemption_rejected", &request.station, id, request.amount);
+    }
+
+    /// Get a redemption request by id
+    pub fn get_redemption(env: Env, id: u64) -> RedemptionRequest {
+        extend_instance_ttl(&env);
+        read_redemption(&env, id)
+    }
+
+    /// Get all pending redemption requests for a station
+    pub fn get_pending_redemptions(env: Env, station: Address) -> soroban_sdk::Vec<RedemptionRequest> {
+        extend_instance_ttl(&env);
+        let mut pending = soroban_sdk::Vec::new(&env);
+        for id in read_pending_ids(&env, &station).iter() {
+            pending.push_back(read_redemption(&env, id));
+        }
+        pending
+    }
+
+}
+
+/// SEP-41 token interface, shared with the Stellar Asset Contract
+#[contractimpl]
+impl TokenInterface for FuelToken {
+    /// Get the allowance for a spender from an owner
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        extend_instance_ttl(&env);
+        read_allowance(&env, &from, &spender).amount
+    }
+
+    /// Approve a spender to spend tokens on behalf of the caller
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        check_non_negative_amount(amount);
+        extend_instance_ttl(&env);
+        write_allowance(&env, &from, &spender, amount, expiration_ledger);
+        emit_approval(&env, &from, &spender, amount, expiration_ledger);
+    }
+
+    /// Get the balance of an address
+    fn balance(env: Env, id: Address) -> i128 {
+        extend_instance_ttl(&env);
+        read_balance(&env, &id)
+    }
+
+    /// Transfer tokens from the caller to another address
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        require_not_paused(&env, PauseScope::Transfer);
+        from.require_auth();
+        check_non_negative_amount(amount);
         extend_instance_ttl(&env);
         spend_balance(&env, &from, amount);
-        
-        let topics = (soroban_sdk::Symbol::new(&env, "clawback"), &admin, &from);
-        env.events().publish(topics, amount);
+        receive_balance(&env, &to, amount);
+        emit_transfer(&env, &from, &to, amount);
+    }
+
+    /// Transfer tokens from one address to another using an allowance
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        require_not_paused(&env, PauseScope::Transfer);
+        spender.require_auth();
+        check_non_negative_amount(amount);
+        extend_instance_ttl(&env);
+        spend_allowance(&env, &from, &spender, amount);
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+        emit_transfer(&env, &from, &to, amount);
+    }
+
+    /// Burn tokens from an address (requires authorization)
+    fn burn(env: Env, from: Address, amount: i128) {
+        require_not_paused(&env, PauseScope::Burn);
+        from.require_auth();
+        check_non_negative_amount(amount);
+        extend_instance_ttl(&env);
+        burn_balance(&env, &from, amount);
+        emit_burn(&env, &from, amount);
+    }
+
+    /// Burn tokens from an address using an allowance
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        require_not_paused(&env, PauseScope::Burn);
+        spender.require_auth();
+        check_non_negative_amount(amount);
+        extend_instance_ttl(&env);
+        spend_allowance(&env, &from, &spender, amount);
+        burn_balance(&env, &from, amount);
+        emit_burn(&env, &from, amount);
+    }
+
+    /// Get the number of decimals for the token
+    fn decimals(env: Env) -> u32 {
+        read_decimal(&env)
+    }
+
+    /// Get the name of the token
+    fn name(env: Env) -> String {
+        read_name(&env)
+    }
+
+    /// Get the symbol of the token
+    fn symbol(env: Env) -> String {
+        read_symbol(&env)
+    }
+}
+
+impl FuelToken {
+    /// Execute an approved council action and mark the proposal as executed
+    fn execute_council_action(env: &Env, proposal: &mut CouncilProposal) {
+        let admin = read_admin(env);
+        match proposal.action.clone() {
+            CouncilAction::Mint(to, amount) => {
+                require_not_paused(env, PauseScope::Mint);
+                check_non_negative_amount(amount);
+                mint_balance(env, &to, amount);
+                emit_mint(env, &admin, &to, amount);
+            }
+            CouncilAction::Clawback(from, amount) => {
+                check_non_negative_amount(amount);
+                burn_balance(env, &from, amount);
+                emit_clawback(env, &admin, &from, amount);
+            }
+            CouncilAction::SetAdmin(new_admin) => {
+                write_admin(env, &new_admin);
+                remove_pending_admin(env);
+                emit_set_admin(env, &admin, &new_admin);
+            }
+            CouncilAction::SetCouncil(members, threshold) => {
+                write_council(env, &members, threshold);
+            }
+            CouncilAction::DissolveCouncil => {
+                remove_council(env);
+            }
+        }
+        proposal.executed = true;
+
+        let topics = (soroban_sdk::Symbol::new(env, "council_executed"), proposal.id);
+        env.events().publish(topics, proposal.action.clone());
     }
 }
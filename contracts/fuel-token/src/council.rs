@@ -0,0 +1,89 @@
+//! M-of-N admin council for the FUEL token contract
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage_types::{
+    Council, CouncilProposal, DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+/// Check if an admin council is configured
+pub fn has_council(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Council)
+}
+
+/// Read the admin council, panic if none is configured
+pub fn read_council(env: &Env) -> Council {
+    env.storage()
+        .instance()
+        .get(&DataKey::Council)
+        .expect("No admin council configured")
+}
+
+/// Validate and write the admin council
+pub fn write_council(env: &Env, members: &Vec<Address>, threshold: u32) {
+    if threshold == 0 || threshold > members.len() {
+        panic!("Invalid council threshold");
+    }
+    for i in 0..members.len() {
+        let member = members.get(i).unwrap();
+        if members.last_index_of(&member) != Some(i) {
+            panic!("Duplicate council member");
+        }
+    }
+    let epoch: u32 = env.storage().instance().get(&DataKey::CouncilEpoch).unwrap_or(0) + 1;
+    env.storage().instance().set(&DataKey::CouncilEpoch, &epoch);
+    let council = Council {
+        members: members.clone(),
+        threshold,
+        epoch,
+    };
+    env.storage().instance().set(&DataKey::Council, &council);
+}
+
+/// Remove the admin council, returning control to the single admin
+pub fn remove_council(env: &Env) {
+    env.storage().instance().remove(&DataKey::Council);
+}
+
+/// Panic if an admin council is configured.
+/// Direct admin paths are disabled while the council holds control.
+pub fn require_no_council(env: &Env) {
+    if has_council(env) {
+        panic!("Council approval required");
+    }
+}
+
+/// Check the address is a council member and has authorized the call
+pub fn require_council_member(env: &Env, member: &Address) -> Council {
+    member.require_auth();
+    let council = read_council(env);
+    if !council.members.contains(member) {
+        panic!("Unauthorized: caller is not a council member");
+    }
+    council
+}
+
+/// Allocate the next council proposal id
+pub fn next_proposal_id(env: &Env) -> u64 {
+    let key = DataKey::CouncilProposalCount;
+    let id: u64 = env.storage().instance().get(&key).unwrap_or(0) + 1;
+    env.storage().instance().set(&key, &id);
+    id
+}
+
+/// Read a council proposal, panic if it does not exist
+pub fn read_proposal(env: &Env, id: u64) -> CouncilProposal {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CouncilProposal(id))
+        .expect("Proposal not found")
+}
+
+/// Write a council proposal
+pub fn write_proposal(env: &Env, proposal: &CouncilProposal) {
+    let key = DataKey::CouncilProposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
@@ -0,0 +1,12 @@
+//! Typed errors for the FUEL token contract
+
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    MintPaused = 1,
+    TransferPaused = 2,
+    BurnPaused = 3,
+}
@@ -8,9 +8,17 @@
 mod admin;
 mod allowance;
 mod balance;
+mod checkpoint;
 mod contract;
+mod council;
+mod error;
 mod metadata;
+mod pause;
+mod permit;
+mod redemption;
 mod storage_types;
+mod sep41_test;
 mod test;
 
-pub use contract::FuelTokenClient;
+pub use contract::{FuelToken, FuelTokenClient};
+pub use error::TokenError;
@@ -1,35 +1,20 @@
 //! Token metadata for the FUEL token
+//!
+//! Stored through the token SDK under the standard metadata key so Stellar
+//! asset tooling can read it.
 
 use soroban_sdk::{Env, String};
-
-use crate::storage_types::TokenMetadata;
-
-pub const DECIMALS: u32 = 7;
-
-/// Token metadata keys in instance storage
-#[derive(Clone)]
-#[soroban_sdk::contracttype]
-pub enum MetadataKey {
-    Metadata,
-}
+use soroban_token_sdk::metadata::TokenMetadata;
+use soroban_token_sdk::TokenUtils;
 
 /// Initialize token metadata
 pub fn write_metadata(env: &Env, metadata: TokenMetadata) {
-    env.storage()
-        .instance()
-        .set(&MetadataKey::Metadata, &metadata);
+    TokenUtils::new(env).metadata().set_metadata(&metadata);
 }
 
 /// Read token metadata
 pub fn read_metadata(env: &Env) -> TokenMetadata {
-    env.storage()
-        .instance()
-        .get(&MetadataKey::Metadata)
-        .unwrap_or(TokenMetadata {
-            decimal: DECIMALS,
-            name: String::from_str(env, "FuelAnchor Token"),
-            symbol: String::from_str(env, "FUEL"),
-        })
+    TokenUtils::new(env).metadata().get_metadata()
 }
 
 /// Get token decimals
@@ -0,0 +1,36 @@
+//! Circuit breaker for the FUEL token contract
+
+use soroban_sdk::{panic_with_error, Env};
+
+use crate::error::TokenError;
+use crate::storage_types::{DataKey, PauseScope};
+
+/// Check if a scope is paused
+pub fn is_paused(env: &Env, scope: PauseScope) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused(scope))
+        .unwrap_or(false)
+}
+
+/// Pause or unpause a scope
+pub fn write_paused(env: &Env, scope: PauseScope, paused: bool) {
+    let key = DataKey::Paused(scope);
+    if paused {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Panic with the scope's typed error if it is paused
+pub fn require_not_paused(env: &Env, scope: PauseScope) {
+    if is_paused(env, scope) {
+        let error = match scope {
+            PauseScope::Mint => TokenError::MintPaused,
+            PauseScope::Transfer => TokenError::TransferPaused,
+            PauseScope::Burn => TokenError::BurnPaused,
+        };
+        panic_with_error!(env, error);
+    }
+}
@@ -0,0 +1,77 @@
+//! Signature-based allowance approvals for the FUEL token contract
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::storage_types::{DataKey, PermitPayload, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+
+/// Strkey version byte for ed25519 account public keys (`G...`)
+const ACCOUNT_VERSION_BYTE: u8 = 6 << 3;
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Read the next expected permit nonce for an owner
+pub fn read_nonce(env: &Env, owner: &Address) -> i128 {
+    let key = DataKey::Nonce(owner.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Check the nonce matches the next expected one and advance it
+pub fn consume_nonce(env: &Env, owner: &Address, nonce: i128) {
+    let expected = read_nonce(env, owner);
+    if nonce != expected {
+        panic!("Invalid nonce");
+    }
+    let key = DataKey::Nonce(owner.clone());
+    env.storage().persistent().set(&key, &(expected + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+/// Verify the owner's ed25519 signature over the XDR-encoded payload, panic if invalid
+pub fn verify_permit(env: &Env, payload: &PermitPayload, signature: &BytesN<64>) {
+    let message: Bytes = payload.clone().to_xdr(env);
+    env.crypto()
+        .ed25519_verify(&payload.owner_pubkey, &message, signature);
+}
+
+/// CRC16-XModem checksum used by Stellar strkeys
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Derive the Stellar account address (`G...`) for an ed25519 public key
+pub fn account_address(env: &Env, pubkey: &BytesN<32>) -> Address {
+    let mut payload = [0u8; 35];
+    payload[0] = ACCOUNT_VERSION_BYTE;
+    payload[1..33].copy_from_slice(&pubkey.to_array());
+    let checksum = crc16(&payload[..33]);
+    payload[33..].copy_from_slice(&checksum.to_le_bytes());
+
+    // 35 bytes encode to exactly 56 base32 characters without padding
+    let mut strkey = [0u8; 56];
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    let mut out = 0;
+    for byte in payload {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            strkey[out] = BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize];
+            out += 1;
+        }
+    }
+
+    Address::from_string_bytes(&Bytes::from_slice(env, &strkey))
+}
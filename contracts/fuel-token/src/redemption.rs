@@ -0,0 +1,75 @@
+//! Station redemption-for-fiat escrow for the FUEL token contract
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage_types::{
+    DataKey, RedemptionRequest, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+/// Allocate the next redemption request id
+pub fn next_redemption_id(env: &Env) -> u64 {
+    let key = DataKey::RedemptionCount;
+    let id: u64 = env.storage().instance().get(&key).unwrap_or(0) + 1;
+    env.storage().instance().set(&key, &id);
+    id
+}
+
+/// Read a redemption request, panic if it does not exist
+pub fn read_redemption(env: &Env, id: u64) -> RedemptionRequest {
+    let key = DataKey::Redemption(id);
+    let request = env
+        .storage()
+        .persistent()
+        .get::<DataKey, RedemptionRequest>(&key)
+        .expect("Redemption not found");
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    request
+}
+
+/// Write a redemption request
+pub fn write_redemption(env: &Env, request: &RedemptionRequest) {
+    let key = DataKey::Redemption(request.id);
+    env.storage().persistent().set(&key, request);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+/// Read the pending redemption ids for a station
+pub fn read_pending_ids(env: &Env, station: &Address) -> Vec<u64> {
+    let key = DataKey::PendingRedemptions(station.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+fn write_pending_ids(env: &Env, station: &Address, ids: &Vec<u64>) {
+    let key = DataKey::PendingRedemptions(station.clone());
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, ids);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+}
+
+/// Add a request id to a station's pending list
+pub fn add_pending(env: &Env, station: &Address, id: u64) {
+    let mut ids = read_pending_ids(env, station);
+    ids.push_back(id);
+    write_pending_ids(env, station, &ids);
+}
+
+/// Remove a request id from a station's pending list
+pub fn remove_pending(env: &Env, station: &Address, id: u64) {
+    let mut ids = read_pending_ids(env, station);
+    if let Some(index) = ids.first_index_of(id) {
+        ids.remove(index);
+    }
+    write_pending_ids(env, station, &ids);
+}
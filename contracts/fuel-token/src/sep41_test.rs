@@ -0,0 +1,276 @@
+//! SEP-41 conformance tests for the FUEL token contract
+//!
+//! Every interface method is exercised through the standard `TokenClient`
+//! and checked against the event shapes emitted by the Stellar Asset Contract.
+
+#![cfg(test)]
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::TokenClient,
+    vec, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::contract::{FuelToken, FuelTokenClient};
+
+fn setup<'a>(env: &Env) -> (Address, TokenClient<'a>, FuelTokenClient<'a>) {
+    let admin = Address::generate(env);
+    let contract_id = env.register_contract(None, FuelToken);
+    let fuel = FuelTokenClient::new(env, &contract_id);
+    fuel.initialize(
+        &admin,
+        &7u32,
+        &String::from_str(env, "FuelAnchor Token"),
+        &String::from_str(env, "FUEL"),
+    );
+    (admin, TokenClient::new(env, &contract_id), fuel)
+}
+
+/// The most recent event, wrapped for comparison with `vec!`
+fn last_event(env: &Env) -> Vec<(Address, Vec<Val>, Val)> {
+    let all = env.events().all();
+    all.slice(all.len() - 1..)
+}
+
+#[test]
+fn test_sep41_metadata() {
+    let env = Env::default();
+    let (_, token, _) = setup(&env);
+
+    assert_eq!(token.decimals(), 7);
+    assert_eq!(token.name(), String::from_str(&env, "FuelAnchor Token"));
+    assert_eq!(token.symbol(), String::from_str(&env, "FUEL"));
+}
+
+#[test]
+fn test_sep41_balance_defaults_to_zero() {
+    let env = Env::default();
+    let (_, token, _) = setup(&env);
+
+    assert_eq!(token.balance(&Address::generate(&env)), 0);
+}
+
+#[test]
+fn test_sep41_mint_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, fuel) = setup(&env);
+    let to = Address::generate(&env);
+
+    fuel.mint(&to, &1000);
+
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                token.address.clone(),
+                (symbol_short!("mint"), admin, to.clone()).into_val(&env),
+                1000i128.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(token.balance(&to), 1000);
+}
+
+#[test]
+fn test_sep41_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, token, fuel) = setup(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    fuel.mint(&from, &1000);
+
+    token.transfer(&from, &to, &250);
+
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            from.clone(),
+            soroban_sdk::testutils::AuthorizedInvocation {
+                function: soroban_sdk::testutils::AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    symbol_short!("transfer"),
+                    (&from, &to, 250i128).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            },
+        )]
+    );
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                token.address.clone(),
+                (symbol_short!("transfer"), from.clone(), to.clone()).into_val(&env),
+                250i128.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(token.balance(&from), 750);
+    assert_eq!(token.balance(&to), 250);
+}
+
+#[test]
+fn test_sep41_approve_and_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, token, _) = setup(&env);
+    let from = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    token.approve(&from, &spender, &500, &200);
+
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                token.address.clone(),
+                (Symbol::new(&env, "approve"), from.clone(), spender.clone()).into_val(&env),
+                (500i128, 200u32).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(token.allowance(&from, &spender), 500);
+
+    // Allowances lapse after the expiration ledger
+    env.ledger().with_mut(|li| li.sequence_number = 201);
+    assert_eq!(token.allowance(&from, &spender), 0);
+}
+
+#[test]
+fn test_sep41_transfer_from() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, token, fuel) = setup(&env);
+    let from = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let to = Address::generate(&env);
+    fuel.mint(&from, &1000);
+    token.approve(&from, &spender, &500, &200);
+
+    token.transfer_from(&spender, &from, &to, &300);
+
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                token.address.clone(),
+                (symbol_short!("transfer"), from.clone(), to.clone()).into_val(&env),
+                300i128.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(token.allowance(&from, &spender), 200);
+    assert_eq!(token.balance(&from), 700);
+    assert_eq!(token.balance(&to), 300);
+}
+
+#[test]
+fn test_sep41_burn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, token, fuel) = setup(&env);
+    let from = Address::generate(&env);
+    fuel.mint(&from, &1000);
+
+    token.burn(&from, &400);
+
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                token.address.clone(),
+                (symbol_short!("burn"), from.clone()).into_val(&env),
+                400i128.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(token.balance(&from), 600);
+}
+
+#[test]
+fn test_sep41_burn_from() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, token, fuel) = setup(&env);
+    let from = Address::generate(&env);
+    let spender = Address::generate(&env);
+    fuel.mint(&from, &1000);
+    token.approve(&from, &spender, &500, &200);
+
+    token.burn_from(&spender, &from, &500);
+
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                token.address.clone(),
+                (symbol_short!("burn"), from.clone()).into_val(&env),
+                500i128.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(token.allowance(&from, &spender), 0);
+    assert_eq!(token.balance(&from), 500);
+}
+
+#[test]
+fn test_sep41_clawback_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, fuel) = setup(&env);
+    let from = Address::generate(&env);
+    fuel.mint(&from, &1000);
+
+    fuel.clawback(&from, &100);
+
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                token.address.clone(),
+                (symbol_short!("clawback"), admin, from.clone()).into_val(&env),
+                100i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_sep41_rejects_negative_amounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, token, fuel) = setup(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    fuel.mint(&from, &1000);
+
+    assert!(token.try_transfer(&from, &to, &-1).is_err());
+    assert!(token.try_approve(&from, &to, &-1, &200).is_err());
+    assert!(token.try_burn(&from, &-1).is_err());
+}
+
+#[test]
+fn test_sep41_insufficient_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, token, fuel) = setup(&env);
+    let from = Address::generate(&env);
+    let spender = Address::generate(&env);
+    fuel.mint(&from, &1000);
+    token.approve(&from, &spender, &100, &200);
+
+    assert!(token.try_transfer_from(&spender, &from, &spender, &101).is_err());
+    assert!(token.try_burn_from(&spender, &from, &101).is_err());
+    assert_eq!(token.balance(&from), 1000);
+}
@@ -16,6 +16,42 @@ pub enum DataKey {
     State(Address),
     /// Contract admin address
     Admin,
+    /// Station redemption request by id
+    Redemption(u64),
+    /// Number of redemption requests ever created
+    RedemptionCount,
+    /// Pending redemption request ids for a station
+    PendingRedemptions(Address),
+    /// Admin handover awaiting acceptance
+    PendingAdmin,
+    /// M-of-N admin council
+    Council,
+    /// Council proposal by id
+    CouncilProposal(u64),
+    /// Number of council proposals ever created
+    CouncilProposalCount,
+    /// Number of councils ever configured
+    CouncilEpoch,
+    /// Current total token supply
+    TotalSupply,
+    /// Balance checkpoint history for an address
+    Checkpoints(Address),
+    /// Total supply checkpoint history
+    SupplyCheckpoints,
+    /// Circuit breaker state per pause scope
+    Paused(PauseScope),
+}
+
+/// Operations that can be paused independently
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum PauseScope {
+    /// `mint`, `batch_mint` and council mints
+    Mint,
+    /// `transfer`, `transfer_from` and `batch_transfer`
+    Transfer,
+    /// `burn`, `burn_from` and station redemptions
+    Burn,
 }
 
 /// Allowance storage key
@@ -34,13 +70,108 @@ pub struct AllowanceValue {
     pub expiration_ledger: u32,
 }
 
-/// Token metadata structure
-#[derive(Clone)]
+/// Lifecycle of a station redemption request
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum RedemptionStatus {
+    /// Tokens held in escrow awaiting off-chain payout
+    Pending,
+    /// Payout confirmed, escrowed tokens burned
+    Confirmed,
+    /// Request rejected, escrowed tokens returned to the station
+    Rejected,
+}
+
+/// Station request to cash out FUEL for fiat
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RedemptionRequest {
+    pub id: u64,
+    pub station: Address,
+    pub amount: i128,
+    /// Off-chain payout reference (bank or mobile money transaction)
+    pub payout_reference: soroban_sdk::String,
+    pub status: RedemptionStatus,
+    pub requested_at: u64,
+    pub resolved_at: u64,
+}
+
+/// Admin handover proposed by the current admin
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PendingAdmin {
+    pub new_admin: Address,
+    /// Last ledger on which the handover can be accepted
+    pub expiration_ledger: u32,
+}
+
+/// M-of-N admin council configuration
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Council {
+    pub members: soroban_sdk::Vec<Address>,
+    /// Approvals required to execute a proposal
+    pub threshold: u32,
+    /// Bumped every time a council is configured; proposals from earlier
+    /// councils cannot be approved
+    pub epoch: u32,
+}
+
+/// Privileged actions that require council approval
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum CouncilAction {
+    Mint(Address, i128),
+    Clawback(Address, i128),
+    SetAdmin(Address),
+    SetCouncil(soroban_sdk::Vec<Address>, u32),
+    DissolveCouncil,
+}
+
+/// Council proposal with the approvals recorded so far
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CouncilProposal {
+    pub id: u64,
+    pub action: CouncilAction,
+    pub approvals: soroban_sdk::Vec<Address>,
+    pub executed: bool,
+    pub created_at: u64,
+    /// Epoch of the council the proposal was made to
+    pub epoch: u32,
+}
+
+/// Payload an owner signs to grant an allowance through `permit`.
+/// Binding the network id and contract address prevents replay on other
+/// networks or token deployments.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PermitPayload {
+    /// SHA-256 of the network passphrase
+    pub network_id: soroban_sdk::BytesN<32>,
+    pub contract: Address,
+    pub owner_pubkey: soroban_sdk::BytesN<32>,
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+    pub nonce: i128,
+}
+
+/// Value recorded at the end of a ledger
+#[derive(Clone, Debug, PartialEq)]
 #[contracttype]
-pub struct TokenMetadata {
-    pub decimal: u32,
-    pub name: soroban_sdk::String,
-    pub symbol: soroban_sdk::String,
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub value: i128,
+}
+
+/// Checkpoint history with the point before which it was truncated by compaction
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CheckpointHistory {
+    /// History before this ledger is no longer retained
+    pub retained_from: u32,
+    pub checkpoints: soroban_sdk::Vec<Checkpoint>,
 }
 
 /// Fleet operator spending rules
@@ -72,5 +203,33 @@ pub const DAY_LEDGERS: u32 = 17280; // ~1 day in ledgers (5 sec/ledger)
 pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_LEDGERS;
 pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_LEDGERS;
 
+/// Network limit on ledger entries a single transaction may write
+/// (`tx_max_write_ledger_entries`).
+pub const TX_MAX_WRITE_LEDGER_ENTRIES: u32 = 25;
+
+/// Entries a batch writes regardless of its size: the authorizer's nonce plus
+/// the sender's balance and checkpoints (`batch_transfer`), or instance storage
+/// and the supply checkpoints (`batch_clawback`).
+pub const BATCH_FIXED_WRITES: u32 = 3;
+
+/// Entries each batch item writes: a balance and its checkpoint history.
+pub const BATCH_ITEM_WRITES: u32 = 2;
+
+/// Largest batch accepted by `batch_transfer` and `batch_clawback`: as many
+/// items as fit under the write-entry limit. A checkpoint history is a single
+/// entry holding up to `MAX_CHECKPOINTS` checkpoints, rewritten whole on every
+/// balance change, so each item also adds up to a few KiB to the transaction's
+/// write bytes.
+pub const MAX_BATCH_SIZE: u32 =
+    (TX_MAX_WRITE_LEDGER_ENTRIES - BATCH_FIXED_WRITES) / BATCH_ITEM_WRITES;
+
+/// Checkpoints kept per history before compaction kicks in
+pub const MAX_CHECKPOINTS: u32 = 100;
+
+pub const ADMIN_HANDOVER_LEDGERS: u32 = 3 * DAY_LEDGERS;
+
+/// Seconds after `created_at` during which a council proposal can be approved
+pub const COUNCIL_PROPOSAL_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
 pub const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_LEDGERS;
 pub const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_LEDGERS;
@@ -3,8 +3,19 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+use soroban_env_host::storage::AccessType;
 use crate::contract::{FuelToken, FuelTokenClient};
+use crate::error::TokenError;
+use crate::storage_types::{
+    CouncilAction, PauseScope, PermitPayload, RedemptionStatus, ADMIN_HANDOVER_LEDGERS,
+    BATCH_FIXED_WRITES, BATCH_ITEM_WRITES, COUNCIL_PROPOSAL_LIFETIME, DAY_LEDGERS, MAX_BATCH_SIZE,
+    MAX_CHECKPOINTS,
+    TX_MAX_WRITE_LEDGER_ENTRIES,
+};
 
 fn create_token<'a>(env: &Env, admin: &Address) -> FuelTokenClient<'a> {
     let contract_id = env.register_contract(None, FuelToken);
@@ -150,3 +161,698 @@ fn test_insufficient_balance() {
     token.mint(&user1, &100);
     token.transfer(&user1, &user2, &200); // Should panic
 }
+
+#[test]
+fn test_redemption_confirm() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let station = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.mint(&station, &1000);
+    let id = token.request_redemption(&station, &600, &String::from_str(&env, "MPESA-001"));
+
+    assert_eq!(token.balance(&station), 400);
+    assert_eq!(token.balance(&token.address), 600);
+    assert_eq!(token.get_pending_redemptions(&station).len(), 1);
+
+    token.confirm_redemption(&id);
+
+    let request = token.get_redemption(&id);
+    assert_eq!(request.status, RedemptionStatus::Confirmed);
+    assert_eq!(token.balance(&token.address), 0);
+    assert_eq!(token.balance(&station), 400);
+    assert_eq!(token.get_pending_redemptions(&station).len(), 0);
+}
+
+#[test]
+fn test_redemption_reject() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let station = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.mint(&station, &1000);
+    let first = token.request_redemption(&station, &300, &String::from_str(&env, "BANK-1"));
+    let second = token.request_redemption(&station, &200, &String::from_str(&env, "BANK-2"));
+
+    token.reject_redemption(&first);
+
+    assert_eq!(token.get_redemption(&first).status, RedemptionStatus::Rejected);
+    assert_eq!(token.balance(&station), 800);
+    assert_eq!(token.balance(&token.address), 200);
+
+    let pending = token.get_pending_redemptions(&station);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().id, second);
+}
+
+#[test]
+#[should_panic(expected = "Redemption is not pending")]
+fn test_redemption_double_confirm() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let station = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.mint(&station, &1000);
+    let id = token.request_redemption(&station, &500, &String::from_str(&env, "MPESA-002"));
+    token.confirm_redemption(&id);
+    token.reject_redemption(&id); // Should panic
+}
+
+#[test]
+fn test_two_step_admin_handover() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.propose_admin(&new_admin);
+    assert_eq!(token.admin(), admin);
+    assert_eq!(token.pending_admin().unwrap().new_admin, new_admin);
+
+    token.accept_admin();
+    assert_eq!(token.admin(), new_admin);
+    assert!(token.pending_admin().is_none());
+}
+
+#[test]
+#[should_panic(expected = "Admin handover expired")]
+fn test_admin_handover_expired() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.set_admin(&new_admin);
+    env.ledger()
+        .with_mut(|li| li.sequence_number += ADMIN_HANDOVER_LEDGERS + 1);
+    token.accept_admin(); // Should panic
+}
+
+#[test]
+fn test_council_mint_requires_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let members = soroban_sdk::vec![&env, member1.clone(), member2.clone(), member3.clone()];
+    token.set_council(&members, &2);
+
+    let id = token.propose_council_action(&member1, &CouncilAction::Mint(user.clone(), 500));
+    assert_eq!(token.balance(&user), 0);
+    assert!(!token.get_council_proposal(&id).executed);
+
+    assert!(token.approve_council_action(&member3, &id));
+    assert_eq!(token.balance(&user), 500);
+    assert!(token.get_council_proposal(&id).executed);
+}
+
+#[test]
+#[should_panic(expected = "Proposal made to a previous council")]
+fn test_council_change_voids_open_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let newcomer = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let members = soroban_sdk::vec![&env, member1.clone(), member2.clone(), member3.clone()];
+    token.set_council(&members, &2);
+    let mint = token.propose_council_action(&member1, &CouncilAction::Mint(user.clone(), 500));
+
+    // member1 is replaced while the mint is open
+    let new_members = soroban_sdk::vec![&env, member2.clone(), member3.clone(), newcomer.clone()];
+    let change = token.propose_council_action(
+        &member2,
+        &CouncilAction::SetCouncil(new_members, 2),
+    );
+    token.approve_council_action(&member3, &change);
+    assert_eq!(token.council().epoch, 2);
+
+    token.approve_council_action(&newcomer, &mint); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Proposal expired")]
+fn test_council_proposal_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.set_council(&soroban_sdk::vec![&env, member1.clone(), member2.clone()], &2);
+    let id = token.propose_council_action(&member1, &CouncilAction::Mint(user, 500));
+    env.ledger()
+        .with_mut(|li| li.timestamp += COUNCIL_PROPOSAL_LIFETIME + 1);
+    token.approve_council_action(&member2, &id); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Council approval required")]
+fn test_council_blocks_direct_mint() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.set_council(&soroban_sdk::vec![&env, member1, member2], &2);
+    token.mint(&user, &100); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Council approval required")]
+fn test_council_cancels_pending_admin_handover() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.propose_admin(&new_admin);
+    token.set_council(&soroban_sdk::vec![&env, member1, member2], &2);
+    assert!(token.pending_admin().is_none());
+    token.accept_admin(); // Should panic
+}
+
+#[test]
+fn test_batch_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let driver1 = Address::generate(&env);
+    let driver2 = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.mint(&operator, &1000);
+    let recipients = soroban_sdk::vec![&env, driver1.clone(), driver2.clone()];
+    let amounts = soroban_sdk::vec![&env, 250i128, 150i128];
+    token.batch_transfer(&operator, &recipients, &amounts);
+
+    assert_eq!(token.balance(&operator), 600);
+    assert_eq!(token.balance(&driver1), 250);
+    assert_eq!(token.balance(&driver2), 150);
+}
+
+#[test]
+fn test_batch_transfer_is_all_or_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let driver1 = Address::generate(&env);
+    let driver2 = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.mint(&operator, &300);
+    let recipients = soroban_sdk::vec![&env, driver1.clone(), driver2.clone()];
+    let amounts = soroban_sdk::vec![&env, 200i128, 200i128];
+    assert!(token.try_batch_transfer(&operator, &recipients, &amounts).is_err());
+
+    assert_eq!(token.balance(&operator), 300);
+    assert_eq!(token.balance(&driver1), 0);
+}
+
+#[test]
+fn test_batch_clawback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.mint(&user1, &500);
+    token.mint(&user2, &500);
+    let accounts = soroban_sdk::vec![&env, user1.clone(), user2.clone()];
+    let amounts = soroban_sdk::vec![&env, 500i128, 100i128];
+    token.batch_clawback(&accounts, &amounts);
+
+    assert_eq!(token.balance(&user1), 0);
+    assert_eq!(token.balance(&user2), 400);
+}
+
+#[test]
+#[should_panic(expected = "Recipients and amounts length mismatch")]
+fn test_batch_clawback_length_mismatch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let accounts = soroban_sdk::vec![&env, user];
+    let amounts = soroban_sdk::vec![&env, 1i128, 2i128];
+    token.batch_clawback(&accounts, &amounts); // Should panic
+}
+
+/// Ledger entries written since the footprint was last reset.
+fn written_entries(env: &Env) -> u32 {
+    let budget = env.host().budget_cloned();
+    env.host()
+        .with_mut_storage(|storage| {
+            Ok(storage
+                .footprint
+                .0
+                .iter(&budget)?
+                .filter(|(_, access)| *access == AccessType::ReadWrite)
+                .count() as u32)
+        })
+        .unwrap()
+}
+
+fn reset_footprint(env: &Env) {
+    env.host()
+        .with_mut_storage(|storage| {
+            storage.footprint = Default::default();
+            Ok(())
+        })
+        .unwrap();
+}
+
+fn batch_transfer_writes(size: u32) -> u32 {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let mut recipients = soroban_sdk::Vec::new(&env);
+    let mut amounts = soroban_sdk::Vec::new(&env);
+    for _ in 0..size {
+        recipients.push_back(Address::generate(&env));
+        amounts.push_back(10i128);
+    }
+    token.mint(&operator, &(10 * size as i128));
+
+    // The default budget mirrors the network per-transaction CPU and memory limits
+    env.budget().reset_default();
+    reset_footprint(&env);
+    token.batch_transfer(&operator, &recipients, &amounts);
+    assert!(env.budget().cpu_instruction_cost() < 100_000_000);
+    assert!(env.budget().memory_bytes_cost() < 40 * 1024 * 1024);
+    assert_eq!(token.balance(&operator), 0);
+    written_entries(&env)
+}
+
+fn batch_clawback_writes(size: u32) -> u32 {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let mut accounts = soroban_sdk::Vec::new(&env);
+    let mut amounts = soroban_sdk::Vec::new(&env);
+    for _ in 0..size {
+        let account = Address::generate(&env);
+        token.mint(&account, &10);
+        accounts.push_back(account);
+        amounts.push_back(10i128);
+    }
+
+    env.budget().reset_default();
+    reset_footprint(&env);
+    token.batch_clawback(&accounts, &amounts);
+    assert!(env.budget().cpu_instruction_cost() < 100_000_000);
+    assert!(env.budget().memory_bytes_cost() < 40 * 1024 * 1024);
+    assert_eq!(token.balance(&accounts.get(0).unwrap()), 0);
+    written_entries(&env)
+}
+
+#[test]
+fn test_batch_transfer_max_size_fits_budget() {
+    let per_item = batch_transfer_writes(2) - batch_transfer_writes(1);
+    assert_eq!(per_item, BATCH_ITEM_WRITES);
+
+    let written = batch_transfer_writes(MAX_BATCH_SIZE);
+    assert_eq!(written, BATCH_FIXED_WRITES + MAX_BATCH_SIZE * BATCH_ITEM_WRITES);
+    assert!(written <= TX_MAX_WRITE_LEDGER_ENTRIES);
+    // One more recipient would exceed the write-entry limit
+    assert!(written + per_item > TX_MAX_WRITE_LEDGER_ENTRIES);
+}
+
+#[test]
+fn test_batch_clawback_max_size_fits_budget() {
+    let per_item = batch_clawback_writes(2) - batch_clawback_writes(1);
+    assert_eq!(per_item, BATCH_ITEM_WRITES);
+
+    let written = batch_clawback_writes(MAX_BATCH_SIZE);
+    assert_eq!(written, BATCH_FIXED_WRITES + MAX_BATCH_SIZE * BATCH_ITEM_WRITES);
+    assert!(written <= TX_MAX_WRITE_LEDGER_ENTRIES);
+    // One more account would exceed the write-entry limit
+    assert!(written + per_item > TX_MAX_WRITE_LEDGER_ENTRIES);
+}
+
+#[test]
+#[should_panic(expected = "Batch too large")]
+fn test_batch_transfer_over_max_size() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let mut recipients = soroban_sdk::Vec::new(&env);
+    let mut amounts = soroban_sdk::Vec::new(&env);
+    for _ in 0..=MAX_BATCH_SIZE {
+        recipients.push_back(Address::generate(&env));
+        amounts.push_back(1i128);
+    }
+    token.batch_transfer(&operator, &recipients, &amounts); // Should panic
+}
+
+#[test]
+fn test_balance_at_checkpoints() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    token.mint(&user1, &1000);
+
+    env.ledger().with_mut(|li| li.sequence_number = 200);
+    token.transfer(&user1, &user2, &300);
+
+    env.ledger().with_mut(|li| li.sequence_number = 300);
+    token.burn(&user2, &100);
+
+    assert_eq!(token.balance_at(&user1, &99), 0);
+    assert_eq!(token.balance_at(&user1, &100), 1000);
+    assert_eq!(token.balance_at(&user1, &199), 1000);
+    assert_eq!(token.balance_at(&user1, &200), 700);
+    assert_eq!(token.balance_at(&user2, &250), 300);
+    assert_eq!(token.balance_at(&user2, &300), 200);
+
+    assert_eq!(token.total_supply_at(&99), 0);
+    assert_eq!(token.total_supply_at(&150), 1000);
+    assert_eq!(token.total_supply_at(&300), 900);
+    assert_eq!(token.total_supply(), 900);
+}
+
+#[test]
+fn test_checkpoint_compaction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    env.budget().reset_unlimited();
+
+    // Ten balance changes per day for many days, well over MAX_CHECKPOINTS
+    let mut sequence = 0;
+    for _day in 0..20 {
+        for _ in 0..10 {
+            sequence += 100;
+            env.ledger().with_mut(|li| li.sequence_number = sequence);
+            token.mint(&user, &1);
+        }
+        sequence += DAY_LEDGERS - 1000;
+    }
+
+    // End-of-day balances survive compaction
+    assert_eq!(token.balance_at(&user, &(DAY_LEDGERS - 1)), 10);
+    assert_eq!(token.balance_at(&user, &(5 * DAY_LEDGERS - 1)), 50);
+    assert_eq!(token.balance_at(&user, &sequence), 200);
+    assert_eq!(token.balance(&user), 200);
+}
+
+#[test]
+fn test_checkpoint_compaction_busy_day_keeps_earlier_days() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    env.budget().reset_unlimited();
+
+    // One change on each of the first three days
+    for day in 0..3 {
+        env.ledger()
+            .with_mut(|li| li.sequence_number = day * DAY_LEDGERS + 10);
+        token.mint(&user, &1);
+    }
+
+    // Then more than MAX_CHECKPOINTS changes within a single day
+    let busy_day = 3 * DAY_LEDGERS;
+    for i in 0..(MAX_CHECKPOINTS + 50) {
+        env.ledger()
+            .with_mut(|li| li.sequence_number = busy_day + i + 1);
+        token.mint(&user, &1);
+    }
+
+    assert_eq!(token.balance_at(&user, &(DAY_LEDGERS - 1)), 1);
+    assert_eq!(token.balance_at(&user, &(3 * DAY_LEDGERS - 1)), 3);
+    assert_eq!(
+        token.balance_at(&user, &(busy_day + MAX_CHECKPOINTS + 50)),
+        3 + MAX_CHECKPOINTS as i128 + 50
+    );
+    assert_eq!(token.total_supply_at(&(2 * DAY_LEDGERS - 1)), 2);
+}
+
+#[test]
+fn test_checkpoint_compaction_drops_oldest_days() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    env.budget().reset_unlimited();
+
+    // One change every other day, two days more than can be kept
+    for i in 0..(MAX_CHECKPOINTS + 2) {
+        env.ledger()
+            .with_mut(|li| li.sequence_number = 2 * i * DAY_LEDGERS + 10);
+        token.mint(&user, &1);
+    }
+
+    // The first two days are gone, ledgers up to the next change included
+    assert!(token.try_balance_at(&user, &(2 * DAY_LEDGERS + 20)).is_err());
+    assert!(token.try_balance_at(&user, &(4 * DAY_LEDGERS + 9)).is_err());
+    assert_eq!(token.balance_at(&user, &(4 * DAY_LEDGERS + 10)), 3);
+    assert_eq!(token.balance_at(&user, &(5 * DAY_LEDGERS)), 3);
+    assert_eq!(token.balance_at(&user, &(6 * DAY_LEDGERS + 10)), 4);
+    assert_eq!(token.balance(&user), MAX_CHECKPOINTS as i128 + 2);
+}
+
+fn sign_permit(
+    env: &Env,
+    token: &FuelTokenClient,
+    signer: &ed25519_dalek::SigningKey,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: i128,
+) -> soroban_sdk::BytesN<64> {
+    use ed25519_dalek::Signer;
+    use soroban_sdk::xdr::ToXdr;
+    extern crate std;
+
+    let payload = PermitPayload {
+        network_id: env.ledger().network_id(),
+        contract: token.address.clone(),
+        owner_pubkey: soroban_sdk::BytesN::from_array(env, &signer.verifying_key().to_bytes()),
+        spender: spender.clone(),
+        amount,
+        expiration_ledger,
+        nonce,
+    };
+    let message: std::vec::Vec<u8> = payload.to_xdr(env).iter().collect();
+    soroban_sdk::BytesN::from_array(env, &signer.sign(&message).to_bytes())
+}
+
+fn account_for(env: &Env, signer: &ed25519_dalek::SigningKey) -> Address {
+    extern crate std;
+
+    let strkey = stellar_strkey::ed25519::PublicKey(signer.verifying_key().to_bytes()).to_string();
+    Address::from_string(&String::from_str(env, &strkey))
+}
+
+#[test]
+fn test_permit_sets_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let signer = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let owner = account_for(&env, &signer);
+    let pubkey = soroban_sdk::BytesN::from_array(&env, &signer.verifying_key().to_bytes());
+    token.mint(&owner, &1000);
+
+    let signature = sign_permit(&env, &token, &signer, &spender, 400, 1000, 0);
+    token.permit(&pubkey, &spender, &400, &1000, &0, &signature);
+
+    assert_eq!(token.allowance(&owner, &spender), 400);
+    assert_eq!(token.nonce(&owner), 1);
+
+    token.transfer_from(&spender, &owner, &recipient, &150);
+    assert_eq!(token.balance(&recipient), 150);
+    assert_eq!(token.allowance(&owner, &spender), 250);
+}
+
+#[test]
+#[should_panic(expected = "Invalid nonce")]
+fn test_permit_replay_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let signer = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let pubkey = soroban_sdk::BytesN::from_array(&env, &signer.verifying_key().to_bytes());
+
+    let signature = sign_permit(&env, &token, &signer, &spender, 400, 1000, 0);
+    token.permit(&pubkey, &spender, &400, &1000, &0, &signature);
+    token.permit(&pubkey, &spender, &400, &1000, &0, &signature); // Should panic
+}
+
+#[test]
+fn test_permit_rejects_tampered_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let signer = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let pubkey = soroban_sdk::BytesN::from_array(&env, &signer.verifying_key().to_bytes());
+
+    let signature = sign_permit(&env, &token, &signer, &spender, 400, 1000, 0);
+    assert!(token
+        .try_permit(&pubkey, &spender, &4000, &1000, &0, &signature)
+        .is_err());
+}
+
+#[test]
+fn test_permit_bound_to_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let token = create_token(&env, &admin);
+    let other_token = create_token(&env, &admin);
+
+    let signer = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let pubkey = soroban_sdk::BytesN::from_array(&env, &signer.verifying_key().to_bytes());
+
+    let signature = sign_permit(&env, &token, &signer, &spender, 400, 1000, 0);
+    assert!(other_token
+        .try_permit(&pubkey, &spender, &400, &1000, &0, &signature)
+        .is_err());
+}
+
+#[test]
+fn test_pause_transfer_allows_clawback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.mint(&user1, &1000);
+    token.pause(&PauseScope::Transfer);
+    assert!(token.is_paused(&PauseScope::Transfer));
+    assert!(!token.is_paused(&PauseScope::Mint));
+
+    assert_eq!(
+        token.try_transfer(&user1, &user2, &100),
+        Err(Ok(TokenError::TransferPaused.into()))
+    );
+
+    // Minting and clawback are unaffected by a transfer pause
+    token.mint(&user1, &100);
+    token.clawback(&user1, &500);
+    assert_eq!(token.balance(&user1), 600);
+
+    token.unpause(&PauseScope::Transfer);
+    token.transfer(&user1, &user2, &100);
+    assert_eq!(token.balance(&user2), 100);
+}
+
+#[test]
+fn test_pause_mint_and_burn_scopes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let station = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    token.mint(&station, &1000);
+    token.pause(&PauseScope::Mint);
+    token.pause(&PauseScope::Burn);
+
+    assert_eq!(
+        token.try_mint(&station, &1),
+        Err(Ok(TokenError::MintPaused.into()))
+    );
+    assert_eq!(
+        token.try_burn(&station, &1),
+        Err(Ok(TokenError::BurnPaused.into()))
+    );
+    assert_eq!(
+        token.try_request_redemption(&station, &1, &String::from_str(&env, "BANK-1")),
+        Err(Ok(TokenError::BurnPaused.into()))
+    );
+    assert_eq!(token.balance(&station), 1000);
+}
@@ -281,6 +281,19 @@ impl Geofencing {
         }
     }
 
+    /// Check if a point is within a polygon zone
+    pub fn validate_polygon_zone(env: Env, zone_id: BytesN<32>, point: GeoPoint) -> bool {
+        let zone: PolygonZone = env.storage().persistent()
+            .get(&DataKey::PolygonZone(zone_id))
+            .expect("Zone not found");
+
+        if !zone.is_active {
+            return false;
+        }
+
+        Self::point_in_polygon(&point, &zone.vertices)
+    }
+
     /// Check if a point is within a corridor
     pub fn validate_corridor(
         env: Env,
@@ -324,68 +337,6 @@ impl Geofencing {
         min_distance <= corridor.buffer_meters
     }
 
-    /// Calculate distance between two points (in meters)
-    pub fn calculate_distance(point1: &GeoPoint, point2: &GeoPoint) -> u32 {
-        // Simplified Euclidean distance calculation
-        // For more accuracy, implement full Haversine formula
-        let lat_diff = (point1.lat - point2.lat).abs();
-        let lng_diff = (point1.lng - point2.lng).abs();
-
-        // Convert micro-degrees to meters (approximate)
-        let lat_meters = (lat_diff * METERS_PER_DEGREE) / MICRO_DEGREES;
-        let lng_meters = (lng_diff * METERS_PER_DEGREE) / MICRO_DEGREES;
-
-        // Euclidean distance
-        let distance_squared = lat_meters * lat_meters + lng_meters * lng_meters;
-        
-        // Integer square root
-        Self::isqrt(distance_squared as u64) as u32
-    }
-
-    /// Calculate point to line segment distance
-    fn point_to_line_distance(point: &GeoPoint, line_start: &GeoPoint, line_end: &GeoPoint) -> u32 {
-        // Vector from line_start to line_end
-        let line_dx = line_end.lng - line_start.lng;
-        let line_dy = line_end.lat - line_start.lat;
-
-        // Length squared of the line segment
-        let line_len_sq = line_dx * line_dx + line_dy * line_dy;
-
-        if line_len_sq == 0 {
-            // Line is a point
-            return Self::calculate_distance(point, line_start);
-        }
-
-        // Vector from line_start to point
-        let point_dx = point.lng - line_start.lng;
-        let point_dy = point.lat - line_start.lat;
-
-        // Project point onto line, clamping to segment
-        let t = ((point_dx * line_dx + point_dy * line_dy) * 1000 / line_len_sq).max(0).min(1000);
-
-        // Closest point on segment
-        let closest = GeoPoint {
-            lat: line_start.lat + (line_dy * t / 1000),
-            lng: line_start.lng + (line_dx * t / 1000),
-        };
-
-        Self::calculate_distance(point, &closest)
-    }
-
-    /// Integer square root helper
-    fn isqrt(n: u64) -> u64 {
-        if n == 0 {
-            return 0;
-        }
-        let mut x = n;
-        let mut y = (x + 1) / 2;
-        while y < x {
-            x = y;
-            y = (x + n / x) / 2;
-        }
-        x
-    }
-
     /// Assign zones to a fleet operator
     pub fn assign_fleet_zones(
         env: Env,
@@ -497,6 +448,93 @@ impl Geofencing {
     }
 }
 
+/// Geometry helpers (not exported as contract functions)
+impl Geofencing {
+    /// Calculate distance between two points (in meters)
+    pub fn calculate_distance(point1: &GeoPoint, point2: &GeoPoint) -> u32 {
+        // Simplified Euclidean distance calculation
+        // For more accuracy, implement full Haversine formula
+        let lat_diff = (point1.lat - point2.lat).abs();
+        let lng_diff = (point1.lng - point2.lng).abs();
+
+        // Convert micro-degrees to meters (approximate)
+        let lat_meters = (lat_diff * METERS_PER_DEGREE) / MICRO_DEGREES;
+        let lng_meters = (lng_diff * METERS_PER_DEGREE) / MICRO_DEGREES;
+
+        // Euclidean distance
+        let distance_squared = lat_meters * lat_meters + lng_meters * lng_meters;
+        
+        // Integer square root
+        Self::isqrt(distance_squared as u64) as u32
+    }
+
+    /// Calculate point to line segment distance
+    fn point_to_line_distance(point: &GeoPoint, line_start: &GeoPoint, line_end: &GeoPoint) -> u32 {
+        // Vector from line_start to line_end
+        let line_dx = line_end.lng - line_start.lng;
+        let line_dy = line_end.lat - line_start.lat;
+
+        // Length squared of the line segment
+        let line_len_sq = line_dx * line_dx + line_dy * line_dy;
+
+        if line_len_sq == 0 {
+            // Line is a point
+            return Self::calculate_distance(point, line_start);
+        }
+
+        // Vector from line_start to point
+        let point_dx = point.lng - line_start.lng;
+        let point_dy = point.lat - line_start.lat;
+
+        // Project point onto line, clamping to segment
+        let t = ((point_dx * line_dx + point_dy * line_dy) * 1000 / line_len_sq).max(0).min(1000);
+
+        // Closest point on segment
+        let closest = GeoPoint {
+            lat: line_start.lat + (line_dy * t / 1000),
+            lng: line_start.lng + (line_dx * t / 1000),
+        };
+
+        Self::calculate_distance(point, &closest)
+    }
+
+    /// Integer square root helper
+    fn isqrt(n: u64) -> u64 {
+        if n == 0 {
+            return 0;
+        }
+        let mut x = n;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + n / x) / 2;
+        }
+        x
+    }
+
+    /// Ray-casting point-in-polygon test on micro-degree coordinates
+    fn point_in_polygon(point: &GeoPoint, vertices: &Vec<GeoPoint>) -> bool {
+        let n = vertices.len();
+        let mut inside = false;
+        let mut j = n - 1;
+        for i in 0..n {
+            let vi = vertices.get(i).unwrap();
+            let vj = vertices.get(j).unwrap();
+            if (vi.lat > point.lat) != (vj.lat > point.lat) {
+                // Longitude where the edge crosses the point's latitude
+                let cross_lng = vi.lng as i128
+                    + (vj.lng - vi.lng) as i128 * (point.lat - vi.lat) as i128
+                        / (vj.lat - vi.lat) as i128;
+                if (point.lng as i128) < cross_lng {
+                    inside = !inside;
+                }
+            }
+            j = i;
+        }
+        inside
+    }
+}
+
 /// Pre-defined major EAC corridors
 pub mod corridors {
     use super::*;
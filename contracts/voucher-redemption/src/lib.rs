@@ -8,13 +8,17 @@
 
 #![no_std]
 
+mod test;
+
 use soroban_sdk::{
-    contract, contractimpl, contracttype, Address, BytesN, Env, Map, String, Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, token, xdr::ToXdr, Address, Bytes, BytesN, Env,
+    Map, String, Symbol, Vec,
 };
 
 /// Error codes for the redemption contract
-#[derive(Clone, Debug, PartialEq)]
-#[contracttype]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
 pub enum RedemptionError {
     NotInitialized = 1,
     AlreadyInitialized = 2,
@@ -27,6 +31,41 @@ pub enum RedemptionError {
     DriverNotRegistered = 9,
     VoucherExpired = 10,
     InvalidAmount = 11,
+    WeeklyLimitExceeded = 12,
+    StationNotAllowed = 13,
+    StationInactive = 14,
+    DriverDeactivated = 15,
+    StationNotFound = 16,
+    GeofencingNotConfigured = 17,
+    InvalidStationStatus = 18,
+    StationAlreadyExists = 19,
+    RedemptionNotFound = 21,
+    InvalidUtcOffset = 22,
+    MonthlyLimitExceeded = 23,
+    FuelGradeNotSold = 24,
+    InvalidPrice = 25,
+    PriceChangeTooLarge = 26,
+    InvalidEffectiveTime = 27,
+    DisputeNotFound = 28,
+    DisputeAlreadyExists = 29,
+    InvalidDisputeStatus = 30,
+    DisputeWindowClosed = 31,
+    RedemptionAlreadyReversed = 32,
+    RefundNotApproved = 33,
+    SettlementPeriodOpen = 34,
+    SettlementAlreadyClosed = 35,
+    RedemptionTooFrequent = 36,
+    ExceedsTankCapacity = 37,
+    VoucherNotFound = 38,
+    VoucherAlreadyExists = 39,
+    VoucherAlreadyUsed = 40,
+    VoucherAmountExceeded = 41,
+    PaymentNotApproved = 42,
+    DriverAlreadyRegistered = 43,
+    InvalidTimeWindow = 44,
+    OutsideRedemptionWindow = 45,
+    ZoneNotFound = 46,
+    VoucherNotCommitted = 47,
 }
 
 /// GPS coordinates with precision for geofencing
@@ -49,21 +88,139 @@ pub struct Geofence {
     pub radius_meters: u32,
 }
 
-/// Verified fuel station
+/// Where a station's forecourt boundary is defined
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum StationZone {
+    /// The station's own `Geofence`
+    Local,
+    /// Circular zone in the geofencing contract
+    Circular(BytesN<32>),
+    /// Polygon zone in the geofencing contract
+    Polygon(BytesN<32>),
+}
+
+/// Point as understood by the geofencing contract
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct GeoPoint {
+    pub lat: i64,
+    pub lng: i64,
+}
+
+/// Circular zone check result returned by the geofencing contract
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ValidationResult {
+    pub is_valid: bool,
+    pub zone_id: BytesN<32>,
+    pub zone_name: String,
+    pub distance_from_center: u32,
+    pub validation_time: u64,
+}
+
+/// Fuel grade sold by stations and taken by vehicles
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum FuelGrade {
+    Petrol,
+    Diesel,
+    Kerosene,
+}
+
+/// Admin-configured limits on a fuel grade's price
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PriceBounds {
+    pub min_price: i128,
+    pub max_price: i128,
+    /// Maximum change per update in basis points of the previous price
+    pub max_change_bps: u32,
+}
+
+/// A station price for one grade and the time it takes effect
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PricePoint {
+    /// Zero when the grade was withdrawn from sale
+    pub price: i128,
+    pub effective_from: u64,
+}
+
+/// Fraud rules checked on every redemption; the value is the rule id
+/// reported in `fraud_flag` events
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum FraudRule {
+    /// Minimum seconds between a driver's redemptions
+    MinInterval = 1,
+    /// Maximum liters per fill, in basis points of the vehicle's tank capacity
+    TankCapacity = 2,
+}
+
+/// What happens when a fraud rule is broken
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum RuleAction {
+    Disabled,
+    /// Allow the redemption and emit a `fraud_flag` event
+    Flag,
+    /// Fail the redemption
+    Reject,
+}
+
+/// Admin configuration of a fraud rule
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RuleConfig {
+    pub action: RuleAction,
+    /// Seconds for `MinInterval`, basis points of tank capacity for `TankCapacity`
+    pub threshold: u64,
+}
+
+/// Station verification lifecycle
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum StationStatus {
+    /// Application submitted, awaiting admin review
+    Pending,
+    /// Approved by the admin; the only status that accepts redemptions
+    Verified,
+    /// Application declined; the owner may resubmit
+    Rejected,
+    /// Temporarily blocked, can be reinstated
+    Suspended,
+    /// Permanently blocked
+    Revoked,
+}
+
+/// Partner fuel station
 #[derive(Clone, Debug, PartialEq)]
 #[contracttype]
 pub struct Station {
     pub id: BytesN<32>,
     pub name: String,
     pub owner: Address,
+    /// Local geofence, used only when no geofencing zone is linked
     pub geofence: Geofence,
-    pub is_active: bool,
-    pub fuel_price_per_liter: i128,
+    pub zone: StationZone,
+    pub status: StationStatus,
+    /// Reason given with the latest rejection, suspension or revocation
+    pub status_reason: String,
+    /// Hashes of the KYC documents submitted with the application
+    pub kyc_documents: Vec<BytesN<32>>,
+    /// Latest price per liter for each grade the station sells; it may be
+    /// scheduled for later, see `get_price_history`
+    pub fuel_prices: Map<FuelGrade, i128>,
     pub total_redemptions: u64,
     pub registered_at: u64,
+    pub verified_at: u64,
 }
 
-/// Driver spending configuration
+/// Driver spending configuration.
+/// Windows are calendar-aligned in the fleet's local time: days start at
+/// local midnight, weeks on Monday and months on the 1st.
 #[derive(Clone, Debug, PartialEq)]
 #[contracttype]
 pub struct SpendingLimits {
@@ -73,8 +230,37 @@ pub struct SpendingLimits {
     pub daily_limit: i128,
     /// Maximum weekly spending limit
     pub weekly_limit: i128,
+    /// Maximum calendar-month spending limit
+    pub monthly_limit: i128,
     /// Allowed stations (empty = all verified stations allowed)
     pub allowed_stations: Vec<BytesN<32>>,
+    /// Allowed redemption times (empty = the fleet's default windows). A
+    /// window from minute 0 to 1440 on every day lifts the fleet's defaults.
+    pub allowed_windows: Vec<TimeWindow>,
+}
+
+/// Recurring local-time window in which redemptions are allowed
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TimeWindow {
+    /// Days the window opens on, bit 0 = Monday through bit 6 = Sunday
+    pub days: u32,
+    /// Minutes after local midnight the window opens
+    pub start_minute: u32,
+    /// Minutes after local midnight the window closes (exclusive), up to
+    /// 1440 for the end of the day; a window closing before it opens runs
+    /// past midnight into the next day
+    pub end_minute: u32,
+}
+
+/// Wallet that pays the station when a driver redeems fuel
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum FundingSource {
+    /// FUEL held by the driver
+    DriverWallet,
+    /// FUEL held by the fleet operator, spent through an allowance to this contract
+    FleetWallet,
 }
 
 /// Driver registration with fleet association
@@ -84,28 +270,212 @@ pub struct Driver {
     pub address: Address,
     pub fleet_operator: Address,
     pub vehicle_id: String,
+    /// Fuel grade the driver's vehicle takes
+    pub fuel_grade: FuelGrade,
+    /// Vehicle tank capacity in liters (7 decimals), 0 if unknown
+    pub tank_capacity: i128,
     pub spending_limits: SpendingLimits,
+    pub funding_source: FundingSource,
     pub daily_spent: i128,
     pub weekly_spent: i128,
-    pub last_daily_reset: u64,
-    pub last_weekly_reset: u64,
+    pub monthly_spent: i128,
+    /// Local day index (days since 1970-01-01) that `daily_spent` belongs to
+    pub daily_window: i64,
+    /// Local week index (Monday-based) that `weekly_spent` belongs to
+    pub weekly_window: i64,
+    /// Local month index (year * 12 + month - 1) that `monthly_spent` belongs to
+    pub monthly_window: i64,
     pub is_active: bool,
     pub total_redemptions: u64,
     pub registered_at: u64,
 }
 
+/// Aggregate view of a fleet's drivers and current spend
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct FleetSummary {
+    pub total_drivers: u32,
+    pub active_drivers: u32,
+    pub deactivated_drivers: u32,
+    /// Spend in the fleet's current day
+    pub daily_spent: i128,
+    /// Spend in the fleet's current week
+    pub weekly_spent: i128,
+    /// Spend in the fleet's current month
+    pub monthly_spent: i128,
+}
+
+/// Driver counts and spend totals a fleet keeps up to date as drivers join,
+/// leave and redeem, so summaries never walk the roster
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct FleetStats {
+    /// Also the length of the fleet's roster
+    pub total_drivers: u32,
+    pub active_drivers: u32,
+    pub daily_spent: i128,
+    pub weekly_spent: i128,
+    pub monthly_spent: i128,
+    pub daily_window: i64,
+    pub weekly_window: i64,
+    pub monthly_window: i64,
+}
+
+/// Amounts a driver can still spend in the current windows
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RemainingLimits {
+    pub daily: i128,
+    pub weekly: i128,
+    pub monthly: i128,
+}
+
 /// Redemption transaction record
 #[derive(Clone, Debug, PartialEq)]
 #[contracttype]
 pub struct RedemptionRecord {
+    /// Derived from the network, contract, sequence, driver, station and ledger
     pub id: BytesN<32>,
+    /// Position of the redemption in this contract's redemption count
+    pub sequence: u64,
+    /// Ledger the redemption was made in
+    pub ledger: u32,
     pub driver: Address,
+    pub fleet_operator: Address,
     pub station_id: BytesN<32>,
+    pub fuel_grade: FuelGrade,
+    pub price_per_liter: i128,
     pub amount: i128,
     pub liters: i128,
     pub gps_coords: GpsCoordinates,
     pub timestamp: u64,
     pub vehicle_id: String,
+    /// Wallet the FUEL was paid from
+    pub payer: Address,
+    /// Station owner the FUEL was paid to
+    pub payee: Address,
+    pub status: RedemptionStatus,
+}
+
+/// Lifecycle of a redemption
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum RedemptionStatus {
+    Completed,
+    /// A dispute is open against the redemption
+    Disputed,
+    /// Payment refunded and spend returned to the driver's limits
+    Reversed,
+}
+
+/// Dispute lifecycle
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum DisputeStatus {
+    /// Opened by the driver, awaiting the station's response
+    Open,
+    /// The station has responded, awaiting resolution
+    Responded,
+    /// Resolved in the station's favour; the redemption stands
+    Upheld,
+    /// Resolved in the driver's favour; the redemption was reversed
+    Reversed,
+}
+
+/// A driver's dispute of a redemption
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Dispute {
+    pub redemption_id: BytesN<32>,
+    pub driver: Address,
+    pub station_id: BytesN<32>,
+    pub reason: String,
+    pub station_response: String,
+    pub status: DisputeStatus,
+    pub opened_at: u64,
+    pub resolved_at: u64,
+}
+
+/// Per-station, per-day (UTC) redemption aggregates, frozen into a
+/// settlement statement once the period is closed
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DailyStats {
+    pub station_id: BytesN<32>,
+    /// Day index, days since 1970-01-01 UTC
+    pub day: u64,
+    pub redemption_count: u32,
+    pub total_amount: i128,
+    pub total_liters: i128,
+    /// Reversals made during the day, including of earlier days' redemptions
+    pub reversed_count: u32,
+    pub reversed_amount: i128,
+    pub reversed_liters: i128,
+    /// Hash chain over the ids of the day's redemptions and reversals, in order
+    pub redemptions_hash: BytesN<32>,
+    pub closed: bool,
+    pub closed_at: u64,
+}
+
+/// Stations where a voucher can be redeemed
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum VoucherScope {
+    AnyStation,
+    Station(BytesN<32>),
+}
+
+/// One-time voucher lifecycle
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum VoucherStatus {
+    Active,
+    Redeemed,
+    Cancelled,
+}
+
+/// One-time voucher pre-issued by a driver and redeemed by a station
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Voucher {
+    /// SHA-256 of the secret revealed at redemption
+    pub code_hash: BytesN<32>,
+    pub driver: Address,
+    pub max_amount: i128,
+    pub expires_at: u64,
+    pub scope: VoucherScope,
+    pub status: VoucherStatus,
+    /// Redemption that used the voucher, zero until redeemed
+    pub redemption_id: BytesN<32>,
+}
+
+/// A station's commitment to a voucher secret, made before revealing it:
+/// the SHA-256 of the secret followed by the station id
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VoucherCommitment {
+    pub station_id: BytesN<32>,
+    pub commitment: BytesN<32>,
+}
+
+/// Owner of a redemption history index
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum RedemptionIndex {
+    Driver(Address),
+    Station(BytesN<32>),
+    Fleet(Address),
+}
+
+/// One page of redemption history
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RedemptionPage {
+    pub records: Vec<RedemptionRecord>,
+    /// Cursor to pass to the next call
+    pub next_cursor: u32,
+    /// Whether index entries remain past `next_cursor`
+    pub has_more: bool,
 }
 
 /// Data keys for contract storage
@@ -115,17 +485,74 @@ pub enum DataKey {
     Admin,
     FuelToken,
     Station(BytesN<32>),
+    /// List of station ids owned by an address
     StationByOwner(Address),
     Driver(Address),
-    DriverByFleet(Address),
-    DailyStats(u64),
+    /// Driver address at a position in a fleet's roster
+    FleetDriver(Address, u32),
+    /// Position of a driver in its fleet's roster
+    FleetDriverPosition(Address),
+    FleetStats(Address),
+    /// Station's aggregates for a UTC day
+    DailyStats(BytesN<32>, u64),
     RedemptionCount,
     LastRedemption(Address),
+    Redemption(BytesN<32>),
+    /// Redemption id at a position in a history index
+    RedemptionIndex(RedemptionIndex, u32),
+    RedemptionIndexLen(RedemptionIndex),
+    CreditScore,
+    Geofencing,
+    /// Fleet's UTC offset in seconds, used to align spending windows
+    FleetUtcOffset(Address),
+    /// Fleet's default redemption time windows
+    FleetTimeWindows(Address),
+    PriceBounds(FuelGrade),
+    PriceHistory(BytesN<32>, FuelGrade),
+    Dispute(BytesN<32>),
+    FraudRule(FraudRule),
+    Voucher(BytesN<32>),
+    /// Station commitments to a voucher's secret, oldest first
+    VoucherCommitments(BytesN<32>),
+    /// Whether driver transfers between fleets also need the driver's authorization
+    TransferNeedsDriverConsent,
+}
+
+/// Credit scoring contract interface used to report redemptions
+#[contractclient(name = "CreditScoreClient")]
+pub trait CreditScoreInterface {
+    fn record_transaction(
+        env: Env,
+        recorder: Address,
+        user: Address,
+        amount: i128,
+        station_id: BytesN<32>,
+        timestamp: u64,
+    );
 }
 
-/// Constants for time calculations (ledger-based)
-const LEDGERS_PER_DAY: u64 = 17280; // ~5 seconds per ledger
-const LEDGERS_PER_WEEK: u64 = LEDGERS_PER_DAY * 7;
+/// Constants for time calculations (ledger timestamp based)
+const SECONDS_PER_DAY: i64 = 86_400;
+const MINUTES_PER_DAY: u32 = 1_440;
+/// Day bitmask covering Monday through Sunday
+const ALL_DAYS: u32 = 0b111_1111;
+/// UTC offsets range from UTC-12:00 to UTC+14:00
+const MIN_UTC_OFFSET: i32 = -12 * 3600;
+const MAX_UTC_OFFSET: i32 = 14 * 3600;
+/// Maximum drivers returned by one `get_fleet_drivers` page
+const MAX_PAGE_SIZE: u32 = 50;
+/// Price points kept per station and grade; the oldest are dropped first
+const MAX_PRICE_HISTORY: u32 = 50;
+const BPS_DENOMINATOR: i128 = 10_000;
+/// Drivers may dispute a redemption for 30 days
+const DISPUTE_WINDOW_SECONDS: u64 = 30 * 86_400;
+
+/// Geofencing contract interface used to validate station locations
+#[contractclient(name = "GeofencingClient")]
+pub trait GeofencingInterface {
+    fn validate_circular_zone(env: Env, zone_id: BytesN<32>, point: GeoPoint) -> ValidationResult;
+    fn validate_polygon_zone(env: Env, zone_id: BytesN<32>, point: GeoPoint) -> bool;
+}
 
 /// Haversine distance approximation (simplified for contract use)
 /// Returns distance in meters (approximate)
@@ -158,156 +585,1970 @@ fn calculate_distance(coord1: &GpsCoordinates, coord2: &GpsCoordinates) -> u32 {
     result as u32
 }
 
-#[contract]
-pub struct VoucherRedemption;
+fn read_admin(env: &Env) -> Result<Address, RedemptionError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(RedemptionError::NotInitialized)
+}
 
-#[contractimpl]
-impl VoucherRedemption {
-    /// Initialize the redemption contract
-    pub fn initialize(env: Env, admin: Address, fuel_token: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
-        }
-        
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::FuelToken, &fuel_token);
-        env.storage().instance().set(&DataKey::RedemptionCount, &0u64);
+/// Check the caller is the admin and has authorized the call
+fn require_admin(env: &Env, caller: &Address) -> Result<(), RedemptionError> {
+    caller.require_auth();
+    if *caller != read_admin(env)? {
+        return Err(RedemptionError::Unauthorized);
     }
+    Ok(())
+}
 
-    /// Register a new fuel station
-    pub fn register_station(
-        env: Env,
-        caller: Address,
-        station_id: BytesN<32>,
-        name: String,
-        owner: Address,
-        geofence: Geofence,
-        fuel_price_per_liter: i128,
-    ) {
-        caller.require_auth();
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        
-        if caller != admin && caller != owner {
-            panic!("Unauthorized");
-        }
+fn read_driver(env: &Env, driver_address: &Address) -> Result<Driver, RedemptionError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Driver(driver_address.clone()))
+        .ok_or(RedemptionError::DriverNotRegistered)
+}
 
-        let station = Station {
-            id: station_id.clone(),
-            name,
-            owner: owner.clone(),
-            geofence,
-            is_active: true,
-            fuel_price_per_liter,
-            total_redemptions: 0,
-            registered_at: env.ledger().timestamp(),
-        };
+fn read_station(env: &Env, station_id: &BytesN<32>) -> Result<Station, RedemptionError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Station(station_id.clone()))
+        .ok_or(RedemptionError::StationNotFound)
+}
 
-        env.storage().persistent().set(&DataKey::Station(station_id.clone()), &station);
-        env.storage().persistent().set(&DataKey::StationByOwner(owner), &station_id);
+fn read_owner_stations(env: &Env, owner: &Address) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StationByOwner(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
 
-        // Emit event
-        env.events().publish(
-            (Symbol::new(&env, "station_registered"), station_id),
-            station.name,
-        );
+fn add_owner_station(env: &Env, owner: &Address, station_id: &BytesN<32>) {
+    let mut stations = read_owner_stations(env, owner);
+    if !stations.contains(station_id) {
+        stations.push_back(station_id.clone());
+        env.storage().persistent().set(&DataKey::StationByOwner(owner.clone()), &stations);
     }
+}
 
-    /// Register a driver for a fleet
-    pub fn register_driver(
-        env: Env,
-        fleet_operator: Address,
-        driver_address: Address,
-        vehicle_id: String,
-        spending_limits: SpendingLimits,
-    ) {
-        fleet_operator.require_auth();
+fn remove_owner_station(env: &Env, owner: &Address, station_id: &BytesN<32>) {
+    let key = DataKey::StationByOwner(owner.clone());
+    let mut stations = read_owner_stations(env, owner);
+    if let Some(index) = stations.first_index_of(station_id) {
+        stations.remove(index);
+    }
+    if stations.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &stations);
+    }
+}
 
-        let driver = Driver {
-            address: driver_address.clone(),
-            fleet_operator: fleet_operator.clone(),
-            vehicle_id,
-            spending_limits,
-            daily_spent: 0,
-            weekly_spent: 0,
-            last_daily_reset: env.ledger().sequence() as u64,
-            last_weekly_reset: env.ledger().sequence() as u64,
-            is_active: true,
-            total_redemptions: 0,
-            registered_at: env.ledger().timestamp(),
-        };
+fn geofencing_client(env: &Env) -> Result<GeofencingClient<'_>, RedemptionError> {
+    let geofencing: Address = env.storage().instance().get(&DataKey::Geofencing)
+        .ok_or(RedemptionError::GeofencingNotConfigured)?;
+    Ok(GeofencingClient::new(env, &geofencing))
+}
 
-        env.storage().persistent().set(&DataKey::Driver(driver_address.clone()), &driver);
+/// Check a location against `zone`: the station's local geofence, or a zone
+/// in the geofencing contract. Zones the geofencing contract doesn't know fail
+/// with `ZoneNotFound`.
+fn zone_contains(
+    env: &Env,
+    station: &Station,
+    zone: &StationZone,
+    gps_coords: &GpsCoordinates,
+) -> Result<bool, RedemptionError> {
+    let point = GeoPoint {
+        lat: gps_coords.latitude,
+        lng: gps_coords.longitude,
+    };
+    match zone {
+        StationZone::Local => Ok(
+            calculate_distance(gps_coords, &station.geofence.center) <= station.geofence.radius_meters,
+        ),
+        StationZone::Circular(zone_id) => {
+            match geofencing_client(env)?.try_validate_circular_zone(zone_id, &point) {
+                Ok(Ok(result)) => Ok(result.is_valid),
+                _ => Err(RedemptionError::ZoneNotFound),
+            }
+        }
+        StationZone::Polygon(zone_id) => {
+            match geofencing_client(env)?.try_validate_polygon_zone(zone_id, &point) {
+                Ok(Ok(is_valid)) => Ok(is_valid),
+                _ => Err(RedemptionError::ZoneNotFound),
+            }
+        }
+    }
+}
 
-        // Emit event
-        env.events().publish(
-            (Symbol::new(&env, "driver_registered"), driver_address),
-            fleet_operator,
-        );
+/// Check the redemption location against the station's linked geofencing
+/// zone, or its local geofence when no zone is linked
+fn check_station_location(
+    env: &Env,
+    station: &Station,
+    gps_coords: &GpsCoordinates,
+) -> Result<(), RedemptionError> {
+    if !zone_contains(env, station, &station.zone, gps_coords)? {
+        return Err(RedemptionError::OutOfGeofence);
     }
+    Ok(())
+}
 
-    /// Update driver spending limits
-    pub fn update_spending_limits(
-        env: Env,
-        fleet_operator: Address,
-        driver_address: Address,
-        new_limits: SpendingLimits,
-    ) {
-        fleet_operator.require_auth();
+/// Read a driver and check it belongs to the given fleet operator
+fn read_fleet_driver(
+    env: &Env,
+    fleet_operator: &Address,
+    driver_address: &Address,
+) -> Result<Driver, RedemptionError> {
+    let driver = read_driver(env, driver_address)?;
+    if driver.fleet_operator != *fleet_operator {
+        return Err(RedemptionError::Unauthorized);
+    }
+    Ok(driver)
+}
 
-        let key = DataKey::Driver(driver_address.clone());
-        let mut driver: Driver = env.storage().persistent().get(&key)
-            .expect("Driver not registered");
+fn read_fleet_stats(env: &Env, fleet_operator: &Address) -> FleetStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FleetStats(fleet_operator.clone()))
+        .unwrap_or(FleetStats {
+            total_drivers: 0,
+            active_drivers: 0,
+            daily_spent: 0,
+            weekly_spent: 0,
+            monthly_spent: 0,
+            daily_window: 0,
+            weekly_window: 0,
+            monthly_window: 0,
+        })
+}
 
-        if driver.fleet_operator != fleet_operator {
-            panic!("Unauthorized: not the fleet operator");
-        }
+fn write_fleet_stats(env: &Env, fleet_operator: &Address, stats: &FleetStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FleetStats(fleet_operator.clone()), stats);
+}
 
-        driver.spending_limits = new_limits;
-        env.storage().persistent().set(&key, &driver);
+/// Append a driver to the end of a fleet's roster
+fn add_to_roster(env: &Env, fleet_operator: &Address, driver: &Driver) {
+    let mut stats = read_fleet_stats(env, fleet_operator);
+    let position = stats.total_drivers;
+    env.storage()
+        .persistent()
+        .set(&DataKey::FleetDriver(fleet_operator.clone(), position), &driver.address);
+    env.storage()
+        .persistent()
+        .set(&DataKey::FleetDriverPosition(driver.address.clone()), &position);
+
+    stats.total_drivers += 1;
+    if driver.is_active {
+        stats.active_drivers += 1;
+    }
+    write_fleet_stats(env, fleet_operator, &stats);
+}
 
-        env.events().publish(
-            (Symbol::new(&env, "limits_updated"), driver_address),
-            fleet_operator,
-        );
+/// Remove a driver from a fleet's roster, moving the last driver into its place
+fn remove_from_roster(env: &Env, fleet_operator: &Address, driver: &Driver) {
+    let position_key = DataKey::FleetDriverPosition(driver.address.clone());
+    let Some(position) = env.storage().persistent().get::<_, u32>(&position_key) else {
+        return;
+    };
+    let mut stats = read_fleet_stats(env, fleet_operator);
+    let last = stats.total_drivers - 1;
+    let last_key = DataKey::FleetDriver(fleet_operator.clone(), last);
+    if position != last {
+        let moved: Address = env.storage().persistent().get(&last_key).unwrap();
+        env.storage()
+            .persistent()
+            .set(&DataKey::FleetDriver(fleet_operator.clone(), position), &moved);
+        env.storage()
+            .persistent()
+            .set(&DataKey::FleetDriverPosition(moved), &position);
     }
+    env.storage().persistent().remove(&last_key);
+    env.storage().persistent().remove(&position_key);
 
-    /// Redeem fuel voucher at a station
-    pub fn redeem_fuel(
-        env: Env,
-        driver_address: Address,
-        station_id: BytesN<32>,
-        amount: i128,
-        gps_coords: GpsCoordinates,
-    ) -> RedemptionRecord {
-        driver_address.require_auth();
+    stats.total_drivers = last;
+    if driver.is_active {
+        stats.active_drivers -= 1;
+    }
+    write_fleet_stats(env, fleet_operator, &stats);
+}
 
-        if amount <= 0 {
-            panic!("Invalid amount");
+fn append_to_index(env: &Env, index: RedemptionIndex, redemption_id: &BytesN<32>) {
+    let len_key = DataKey::RedemptionIndexLen(index.clone());
+    let len: u32 = env.storage().persistent().get(&len_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RedemptionIndex(index, len), redemption_id);
+    env.storage().persistent().set(&len_key, &(len + 1));
+}
+
+/// Read a page of an index starting at `cursor`, keeping records whose
+/// timestamp falls within `[from, to]`. At most `MAX_PAGE_SIZE` index
+/// entries are scanned per call, so a filtered page may hold fewer than
+/// `limit` records while `has_more` is still true.
+fn read_index_page(
+    env: &Env,
+    index: RedemptionIndex,
+    cursor: u32,
+    limit: u32,
+    from: Option<u64>,
+    to: Option<u64>,
+) -> RedemptionPage {
+    let len: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RedemptionIndexLen(index.clone()))
+        .unwrap_or(0);
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let end = cursor.saturating_add(MAX_PAGE_SIZE).min(len);
+
+    let mut records = Vec::new(env);
+    let mut position = cursor;
+    while position < end && records.len() < limit {
+        let redemption_id: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RedemptionIndex(index.clone(), position))
+            .unwrap();
+        position += 1;
+
+        let record: RedemptionRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Redemption(redemption_id))
+            .unwrap();
+        let after_from = from.is_none_or(|from| record.timestamp >= from);
+        let before_to = to.is_none_or(|to| record.timestamp <= to);
+        if after_from && before_to {
+            records.push_back(record);
         }
+    }
 
-        // Get driver info
-        let driver_key = DataKey::Driver(driver_address.clone());
-        let mut driver: Driver = env.storage().persistent().get(&driver_key)
-            .expect("Driver not registered");
+    RedemptionPage {
+        records,
+        next_cursor: position,
+        has_more: position < len,
+    }
+}
 
-        if !driver.is_active {
-            panic!("Driver account is deactivated");
-        }
+/// Current day, week and month indexes in a fleet's local time
+struct SpendingWindows {
+    day: i64,
+    week: i64,
+    month: i64,
+}
 
-        // Get station info
-        let station_key = DataKey::Station(station_id.clone());
-        let mut station: Station = env.storage().persistent().get(&station_key)
-            .expect("Station not found");
+fn current_windows(env: &Env, fleet_operator: &Address) -> SpendingWindows {
+    windows_at(env, fleet_operator, env.ledger().timestamp())
+}
+
+/// Timestamp shifted by the fleet's UTC offset
+fn local_timestamp(env: &Env, fleet_operator: &Address, timestamp: u64) -> i64 {
+    let offset: i32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FleetUtcOffset(fleet_operator.clone()))
+        .unwrap_or(0);
+    timestamp as i64 + offset as i64
+}
+
+fn windows_at(env: &Env, fleet_operator: &Address, timestamp: u64) -> SpendingWindows {
+    let local = local_timestamp(env, fleet_operator, timestamp);
+    let day = local.div_euclid(SECONDS_PER_DAY);
+
+    // 1970-01-01 was a Thursday; shift so weeks start on Monday
+    let week = (day + 3).div_euclid(7);
+
+    // Civil date from day count (Howard Hinnant's days_from_civil inverse)
+    let z = day + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    SpendingWindows {
+        day,
+        week,
+        month: year * 12 + month - 1,
+    }
+}
 
-        if !station.is_active {
-            panic!("Station is not active");
+fn check_time_windows(windows: &Vec<TimeWindow>) -> Result<(), RedemptionError> {
+    for window in windows.iter() {
+        if window.days == 0
+            || window.days & !ALL_DAYS != 0
+            || window.start_minute >= MINUTES_PER_DAY
+            || window.end_minute > MINUTES_PER_DAY
+            || window.start_minute == window.end_minute
+        {
+            return Err(RedemptionError::InvalidTimeWindow);
         }
+    }
+    Ok(())
+}
 
-        // Check geofence
-        let distance = calculate_distance(&gps_coords, &station.geofence.center);
-        if distance > station.geofence.radius_meters {
-            panic!("Out of geofence");
+/// Check the current local time falls in one of the driver's windows, or the
+/// fleet's default windows when the driver has none. No windows means any time.
+fn check_redemption_time(env: &Env, driver: &Driver) -> Result<(), RedemptionError> {
+    let mut windows = driver.spending_limits.allowed_windows.clone();
+    if windows.is_empty() {
+        windows = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FleetTimeWindows(driver.fleet_operator.clone()))
+            .unwrap_or(Vec::new(env));
+    }
+    if windows.is_empty() {
+        return Ok(());
+    }
+
+    let local = local_timestamp(env, &driver.fleet_operator, env.ledger().timestamp());
+    let day = local.div_euclid(SECONDS_PER_DAY);
+    let minute = (local.rem_euclid(SECONDS_PER_DAY) / 60) as u32;
+    // 1970-01-01 was a Thursday
+    let weekday = (day + 3).rem_euclid(7) as u32;
+    let previous_weekday = (weekday + 6) % 7;
+
+    for window in windows.iter() {
+        let open = if window.start_minute < window.end_minute {
+            window.days & (1 << weekday) != 0
+                && minute >= window.start_minute
+                && minute < window.end_minute
+        } else {
+            // Overnight: the evening part belongs to today, the early
+            // morning part to the window that opened yesterday
+            (window.days & (1 << weekday) != 0 && minute >= window.start_minute)
+                || (window.days & (1 << previous_weekday) != 0 && minute < window.end_minute)
+        };
+        if open {
+            return Ok(());
         }
+    }
+    Err(RedemptionError::OutsideRedemptionWindow)
+}
+
+/// Zero any spend counters whose window has passed
+fn roll_spending_windows(driver: &mut Driver, windows: &SpendingWindows) {
+    if driver.daily_window != windows.day {
+        driver.daily_spent = 0;
+        driver.daily_window = windows.day;
+    }
+    if driver.weekly_window != windows.week {
+        driver.weekly_spent = 0;
+        driver.weekly_window = windows.week;
+    }
+    if driver.monthly_window != windows.month {
+        driver.monthly_spent = 0;
+        driver.monthly_window = windows.month;
+    }
+}
+
+/// Zero any fleet spend totals whose window has passed
+fn roll_fleet_windows(stats: &mut FleetStats, windows: &SpendingWindows) {
+    if stats.daily_window != windows.day {
+        stats.daily_spent = 0;
+        stats.daily_window = windows.day;
+    }
+    if stats.weekly_window != windows.week {
+        stats.weekly_spent = 0;
+        stats.weekly_window = windows.week;
+    }
+    if stats.monthly_window != windows.month {
+        stats.monthly_spent = 0;
+        stats.monthly_window = windows.month;
+    }
+}
+
+fn read_price_history(env: &Env, station_id: &BytesN<32>, fuel_grade: FuelGrade) -> Vec<PricePoint> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PriceHistory(station_id.clone(), fuel_grade))
+        .unwrap_or(Vec::new(env))
+}
+
+fn append_price_point(env: &Env, station_id: &BytesN<32>, fuel_grade: FuelGrade, point: PricePoint) {
+    let mut history = read_price_history(env, station_id, fuel_grade);
+    history.push_back(point);
+    if history.len() > MAX_PRICE_HISTORY {
+        history.pop_front();
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::PriceHistory(station_id.clone(), fuel_grade), &history);
+}
+
+/// Last price a grade was sold at, skipping withdrawals
+fn last_recorded_price(history: &Vec<PricePoint>) -> Option<i128> {
+    history.iter().rev().map(|point| point.price).find(|price| *price > 0)
+}
+
+/// Record that a grade is withdrawn from sale now, dropping prices scheduled later
+fn withdraw_price(env: &Env, station_id: &BytesN<32>, fuel_grade: FuelGrade) {
+    let now = env.ledger().timestamp();
+    let mut history = read_price_history(env, station_id, fuel_grade);
+    while history.last().is_some_and(|point| point.effective_from > now) {
+        history.pop_back();
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::PriceHistory(station_id.clone(), fuel_grade), &history);
+    append_price_point(
+        env,
+        station_id,
+        fuel_grade,
+        PricePoint {
+            price: 0,
+            effective_from: now,
+        },
+    );
+}
+
+/// Check a price is positive, within the grade's admin-configured bounds and,
+/// when replacing `previous`, within the maximum change per update
+fn check_price(
+    env: &Env,
+    fuel_grade: FuelGrade,
+    price: i128,
+    previous: Option<i128>,
+) -> Result<(), RedemptionError> {
+    if price <= 0 {
+        return Err(RedemptionError::InvalidPrice);
+    }
+    let Some(bounds) = env
+        .storage()
+        .instance()
+        .get::<DataKey, PriceBounds>(&DataKey::PriceBounds(fuel_grade))
+    else {
+        return Ok(());
+    };
+
+    if price < bounds.min_price || price > bounds.max_price {
+        return Err(RedemptionError::InvalidPrice);
+    }
+    if let Some(previous) = previous {
+        let change = (price - previous).abs();
+        if change * BPS_DENOMINATOR > previous * bounds.max_change_bps as i128 {
+            return Err(RedemptionError::PriceChangeTooLarge);
+        }
+    }
+    Ok(())
+}
+
+/// Price of a grade in effect at `timestamp`, from the station's price history
+fn effective_price(
+    env: &Env,
+    station: &Station,
+    fuel_grade: FuelGrade,
+    timestamp: u64,
+) -> Result<i128, RedemptionError> {
+    if !station.fuel_prices.contains_key(fuel_grade) {
+        return Err(RedemptionError::FuelGradeNotSold);
+    }
+
+    // A grade whose first price since being added is still scheduled is not
+    // on sale yet
+    let history = read_price_history(env, &station.id, fuel_grade);
+    match history.iter().rev().find(|point| point.effective_from <= timestamp) {
+        Some(point) if point.price > 0 => Ok(point.price),
+        _ => Err(RedemptionError::FuelGradeNotSold),
+    }
+}
+
+fn read_redemption(env: &Env, redemption_id: &BytesN<32>) -> Result<RedemptionRecord, RedemptionError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Redemption(redemption_id.clone()))
+        .ok_or(RedemptionError::RedemptionNotFound)
+}
+
+fn read_dispute(env: &Env, redemption_id: &BytesN<32>) -> Result<Dispute, RedemptionError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dispute(redemption_id.clone()))
+        .ok_or(RedemptionError::DisputeNotFound)
+}
+
+/// Settlement day index, days since 1970-01-01 UTC
+fn current_utc_day(env: &Env) -> u64 {
+    env.ledger().timestamp() / SECONDS_PER_DAY as u64
+}
+
+fn read_daily_stats(env: &Env, station_id: &BytesN<32>, day: u64) -> DailyStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DailyStats(station_id.clone(), day))
+        .unwrap_or(DailyStats {
+            station_id: station_id.clone(),
+            day,
+            redemption_count: 0,
+            total_amount: 0,
+            total_liters: 0,
+            reversed_count: 0,
+            reversed_amount: 0,
+            reversed_liters: 0,
+            redemptions_hash: BytesN::from_array(env, &[0u8; 32]),
+            closed: false,
+            closed_at: 0,
+        })
+}
+
+/// Add a redemption, or with `reversal` its reversal, to the station's stats
+/// for the current day. The ledger only moves forward, so the current day is
+/// never closed.
+fn record_daily_stats(env: &Env, record: &RedemptionRecord, reversal: bool) {
+    let day = current_utc_day(env);
+    let mut stats = read_daily_stats(env, &record.station_id, day);
+    if reversal {
+        stats.reversed_count += 1;
+        stats.reversed_amount += record.amount;
+        stats.reversed_liters += record.liters;
+    } else {
+        stats.redemption_count += 1;
+        stats.total_amount += record.amount;
+        stats.total_liters += record.liters;
+    }
+
+    let mut preimage = Bytes::from_array(env, &stats.redemptions_hash.to_array());
+    preimage.append(&record.id.clone().into());
+    stats.redemptions_hash = env.crypto().sha256(&preimage).into();
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::DailyStats(record.station_id.clone(), day), &stats);
+}
+
+fn read_rule(env: &Env, rule: FraudRule) -> RuleConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::FraudRule(rule))
+        .unwrap_or(RuleConfig {
+            action: RuleAction::Disabled,
+            threshold: 0,
+        })
+}
+
+/// Apply a broken rule's action: rejected rules fail with `error`, flagged
+/// rules are added to `flags`
+fn apply_rule(
+    env: &Env,
+    rule: FraudRule,
+    flags: &mut Vec<FraudRule>,
+    error: RedemptionError,
+) -> Result<(), RedemptionError> {
+    match read_rule(env, rule).action {
+        RuleAction::Disabled => Ok(()),
+        RuleAction::Flag => {
+            flags.push_back(rule);
+            Ok(())
+        }
+        RuleAction::Reject => Err(error),
+    }
+}
+
+/// Check a redemption of `liters` against the fraud rules, returning the
+/// rules it breaks that are configured to flag
+fn check_fraud_rules(env: &Env, driver: &Driver, liters: i128) -> Result<Vec<FraudRule>, RedemptionError> {
+    let mut flags = Vec::new(env);
+
+    let interval = read_rule(env, FraudRule::MinInterval);
+    if interval.action != RuleAction::Disabled {
+        let last: Option<RedemptionRecord> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::LastRedemption(driver.address.clone()));
+        if let Some(last) = last {
+            if env.ledger().timestamp() < last.timestamp.saturating_add(interval.threshold) {
+                apply_rule(env, FraudRule::MinInterval, &mut flags, RedemptionError::RedemptionTooFrequent)?;
+            }
+        }
+    }
+
+    let capacity = read_rule(env, FraudRule::TankCapacity);
+    if capacity.action != RuleAction::Disabled && driver.tank_capacity > 0 {
+        let max_liters = driver.tank_capacity * capacity.threshold as i128 / BPS_DENOMINATOR;
+        if liters > max_liters {
+            apply_rule(env, FraudRule::TankCapacity, &mut flags, RedemptionError::ExceedsTankCapacity)?;
+        }
+    }
+
+    Ok(flags)
+}
+
+/// Commitment binding a voucher secret to the station that will reveal it
+fn voucher_commitment(env: &Env, secret: &Bytes, station_id: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = secret.clone();
+    preimage.append(&station_id.clone().into());
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Redemption id, unique across networks and deployments: the SHA-256 of the
+/// network id, this contract's address, the redemption sequence, the driver,
+/// the station and the ledger sequence
+fn redemption_id(
+    env: &Env,
+    sequence: u64,
+    driver_address: &Address,
+    station_id: &BytesN<32>,
+    ledger: u32,
+) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &env.ledger().network_id().to_array());
+    preimage.append(&env.current_contract_address().to_xdr(env));
+    preimage.extend_from_array(&sequence.to_be_bytes());
+    preimage.append(&driver_address.clone().to_xdr(env));
+    preimage.append(&station_id.clone().into());
+    preimage.extend_from_array(&ledger.to_be_bytes());
+    env.crypto().sha256(&preimage).into()
+}
+
+#[contract]
+pub struct VoucherRedemption;
+
+#[contractimpl]
+impl VoucherRedemption {
+    /// Initialize the redemption contract
+    pub fn initialize(env: Env, admin: Address, fuel_token: Address) -> Result<(), RedemptionError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(RedemptionError::AlreadyInitialized);
+        }
+        
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::FuelToken, &fuel_token);
+        env.storage().instance().set(&DataKey::RedemptionCount, &0u64);
+        Ok(())
+    }
+
+    /// Set the credit scoring contract that receives every redemption (admin only).
+    /// This contract must be authorized as a recorder there.
+    pub fn set_credit_score_contract(
+        env: Env,
+        admin: Address,
+        credit_score: Address,
+    ) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::CreditScore, &credit_score);
+
+        env.events().publish(
+            (Symbol::new(&env, "credit_score_set"), admin),
+            credit_score,
+        );
+        Ok(())
+    }
+
+    /// Set the geofencing contract used for linked station zones (admin only)
+    pub fn set_geofencing_contract(
+        env: Env,
+        admin: Address,
+        geofencing: Address,
+    ) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::Geofencing, &geofencing);
+
+        env.events().publish(
+            (Symbol::new(&env, "geofencing_set"), admin),
+            geofencing,
+        );
+        Ok(())
+    }
+
+    /// Link a station to a zone in the geofencing contract, or back to its
+    /// local geofence with `StationZone::Local` (admin or station owner).
+    /// The zone must exist in the configured geofencing contract. A verified
+    /// station whose owner changes its zone goes back to `Pending` for review.
+    pub fn link_station_zone(
+        env: Env,
+        caller: Address,
+        station_id: BytesN<32>,
+        zone: StationZone,
+    ) -> Result<(), RedemptionError> {
+        caller.require_auth();
+        let mut station = read_station(&env, &station_id)?;
+        let is_admin = caller == read_admin(&env)?;
+        if !is_admin && caller != station.owner {
+            return Err(RedemptionError::Unauthorized);
+        }
+        zone_contains(&env, &station, &zone, &station.geofence.center)?;
+
+        let needs_review =
+            !is_admin && station.status == StationStatus::Verified && station.zone != zone;
+        station.zone = zone.clone();
+        env.storage().persistent().set(&DataKey::Station(station_id.clone()), &station);
+
+        env.events().publish(
+            (Symbol::new(&env, "station_zone_linked"), station_id.clone()),
+            zone,
+        );
+        if needs_review {
+            Self::transition_station(
+                &env,
+                &station_id,
+                StationStatus::Verified,
+                StationStatus::Pending,
+                String::from_str(&env, "Zone changed by owner"),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Submit a station application (station owner).
+    /// The station starts as `Pending` and cannot accept redemptions until verified.
+    /// Station ids are unique; only the owner of a rejected application may reuse its id.
+    pub fn submit_station_application(
+        env: Env,
+        owner: Address,
+        station_id: BytesN<32>,
+        name: String,
+        geofence: Geofence,
+        fuel_prices: Map<FuelGrade, i128>,
+        kyc_documents: Vec<BytesN<32>>,
+    ) -> Result<(), RedemptionError> {
+        owner.require_auth();
+        read_admin(&env)?;
+
+        if let Ok(existing) = read_station(&env, &station_id) {
+            if existing.status != StationStatus::Rejected || existing.owner != owner {
+                return Err(RedemptionError::StationAlreadyExists);
+            }
+            // Grades dropped from a resubmitted application are withdrawn
+            for fuel_grade in existing.fuel_prices.keys().iter() {
+                if !fuel_prices.contains_key(fuel_grade) {
+                    withdraw_price(&env, &station_id, fuel_grade);
+                }
+            }
+        }
+        for (fuel_grade, price) in fuel_prices.iter() {
+            check_price(&env, fuel_grade, price, None)?;
+        }
+
+        let station = Station {
+            id: station_id.clone(),
+            name,
+            owner: owner.clone(),
+            geofence,
+            zone: StationZone::Local,
+            status: StationStatus::Pending,
+            status_reason: String::from_str(&env, ""),
+            kyc_documents,
+            fuel_prices,
+            total_redemptions: 0,
+            registered_at: env.ledger().timestamp(),
+            verified_at: 0,
+        };
+
+        env.storage().persistent().set(&DataKey::Station(station_id.clone()), &station);
+        add_owner_station(&env, &owner, &station_id);
+        for (fuel_grade, price) in station.fuel_prices.iter() {
+            let history = Vec::from_array(
+                &env,
+                [PricePoint {
+                    price,
+                    effective_from: station.registered_at,
+                }],
+            );
+            env.storage()
+                .persistent()
+                .set(&DataKey::PriceHistory(station_id.clone(), fuel_grade), &history);
+        }
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(&env, "station_application_submitted"), station_id),
+            station.name,
+        );
+        Ok(())
+    }
+
+    /// Transfer a station to a new owner with the new owner's KYC document
+    /// hashes; both owners must authorize. A verified station goes back to
+    /// `Pending` until the admin approves the new owner, who receives
+    /// redemption payments from then on.
+    pub fn transfer_station(
+        env: Env,
+        owner: Address,
+        station_id: BytesN<32>,
+        new_owner: Address,
+        kyc_documents: Vec<BytesN<32>>,
+    ) -> Result<(), RedemptionError> {
+        owner.require_auth();
+        new_owner.require_auth();
+
+        let mut station = read_station(&env, &station_id)?;
+        if station.owner != owner {
+            return Err(RedemptionError::Unauthorized);
+        }
+
+        let needs_review = station.status == StationStatus::Verified;
+        station.owner = new_owner.clone();
+        station.kyc_documents = kyc_documents;
+        env.storage().persistent().set(&DataKey::Station(station_id.clone()), &station);
+        remove_owner_station(&env, &owner, &station_id);
+        add_owner_station(&env, &new_owner, &station_id);
+
+        env.events().publish(
+            (Symbol::new(&env, "station_transferred"), station_id.clone()),
+            (owner, new_owner),
+        );
+        if needs_review {
+            Self::transition_station(
+                &env,
+                &station_id,
+                StationStatus::Verified,
+                StationStatus::Pending,
+                String::from_str(&env, "Ownership transferred"),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Approve a pending station application (admin only)
+    pub fn approve_station(env: Env, admin: Address, station_id: BytesN<32>) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+        Self::transition_station(
+            &env,
+            &station_id,
+            StationStatus::Pending,
+            StationStatus::Verified,
+            String::from_str(&env, ""),
+        )
+    }
+
+    /// Reject a pending station application with a reason (admin only)
+    pub fn reject_station(
+        env: Env,
+        admin: Address,
+        station_id: BytesN<32>,
+        reason: String,
+    ) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+        Self::transition_station(&env, &station_id, StationStatus::Pending, StationStatus::Rejected, reason)
+    }
+
+    /// Temporarily suspend a verified station with a reason (admin only)
+    pub fn suspend_station(
+        env: Env,
+        admin: Address,
+        station_id: BytesN<32>,
+        reason: String,
+    ) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+        Self::transition_station(&env, &station_id, StationStatus::Verified, StationStatus::Suspended, reason)
+    }
+
+    /// Reinstate a suspended station (admin only)
+    pub fn reinstate_station(env: Env, admin: Address, station_id: BytesN<32>) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+        Self::transition_station(
+            &env,
+            &station_id,
+            StationStatus::Suspended,
+            StationStatus::Verified,
+            String::from_str(&env, ""),
+        )
+    }
+
+    /// Permanently revoke a verified or suspended station with a reason (admin only)
+    pub fn revoke_station(
+        env: Env,
+        admin: Address,
+        station_id: BytesN<32>,
+        reason: String,
+    ) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+        let station = read_station(&env, &station_id)?;
+        if station.status == StationStatus::Suspended {
+            Self::transition_station(&env, &station_id, StationStatus::Suspended, StationStatus::Revoked, reason)
+        } else {
+            Self::transition_station(&env, &station_id, StationStatus::Verified, StationStatus::Revoked, reason)
+        }
+    }
+
+    /// Register a driver for a fleet
+    pub fn register_driver(
+        env: Env,
+        fleet_operator: Address,
+        driver_address: Address,
+        vehicle_id: String,
+        fuel_grade: FuelGrade,
+        tank_capacity: i128,
+        spending_limits: SpendingLimits,
+    ) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+        read_admin(&env)?;
+
+        // Another fleet's driver can only move with `transfer_driver`
+        if read_driver(&env, &driver_address).is_ok() {
+            return Err(RedemptionError::DriverAlreadyRegistered);
+        }
+        if tank_capacity < 0 {
+            return Err(RedemptionError::InvalidAmount);
+        }
+        check_time_windows(&spending_limits.allowed_windows)?;
+
+        let windows = current_windows(&env, &fleet_operator);
+        let driver = Driver {
+            address: driver_address.clone(),
+            fleet_operator: fleet_operator.clone(),
+            vehicle_id,
+            fuel_grade,
+            tank_capacity,
+            spending_limits,
+            funding_source: FundingSource::DriverWallet,
+            daily_spent: 0,
+            weekly_spent: 0,
+            monthly_spent: 0,
+            daily_window: windows.day,
+            weekly_window: windows.week,
+            monthly_window: windows.month,
+            is_active: true,
+            total_redemptions: 0,
+            registered_at: env.ledger().timestamp(),
+        };
+
+        add_to_roster(&env, &fleet_operator, &driver);
+        env.storage().persistent().set(&DataKey::Driver(driver_address.clone()), &driver);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(&env, "driver_registered"), driver_address),
+            fleet_operator,
+        );
+        Ok(())
+    }
+
+    /// Move a driver to another fleet; both fleet operators must authorize,
+    /// and the driver too when the admin requires driver consent. Redemption
+    /// counts and history are kept, while spending limits, spend counters and
+    /// funding source start fresh under the new fleet.
+    pub fn transfer_driver(
+        env: Env,
+        from_fleet: Address,
+        to_fleet: Address,
+        driver_address: Address,
+        spending_limits: SpendingLimits,
+    ) -> Result<(), RedemptionError> {
+        from_fleet.require_auth();
+        to_fleet.require_auth();
+
+        let mut driver = read_fleet_driver(&env, &from_fleet, &driver_address)?;
+        if to_fleet == from_fleet {
+            return Err(RedemptionError::DriverAlreadyRegistered);
+        }
+        check_time_windows(&spending_limits.allowed_windows)?;
+        if env
+            .storage()
+            .instance()
+            .get(&DataKey::TransferNeedsDriverConsent)
+            .unwrap_or(false)
+        {
+            driver_address.require_auth();
+        }
+
+        remove_from_roster(&env, &from_fleet, &driver);
+        let windows = current_windows(&env, &to_fleet);
+        driver.fleet_operator = to_fleet.clone();
+        driver.spending_limits = spending_limits;
+        driver.funding_source = FundingSource::DriverWallet;
+        driver.daily_spent = 0;
+        driver.weekly_spent = 0;
+        driver.monthly_spent = 0;
+        driver.daily_window = windows.day;
+        driver.weekly_window = windows.week;
+        driver.monthly_window = windows.month;
+
+        add_to_roster(&env, &to_fleet, &driver);
+        env.storage().persistent().set(&DataKey::Driver(driver_address.clone()), &driver);
+
+        env.events().publish(
+            (Symbol::new(&env, "driver_transferred"), driver_address),
+            (from_fleet, to_fleet),
+        );
+        Ok(())
+    }
+
+    /// Require the driver's authorization on fleet transfers (admin only)
+    pub fn set_transfer_driver_consent(env: Env, admin: Address, required: bool) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::TransferNeedsDriverConsent, &required);
+
+        env.events().publish(
+            (Symbol::new(&env, "transfer_consent_set"), admin),
+            required,
+        );
+        Ok(())
+    }
+
+    /// Update driver spending limits
+    pub fn update_spending_limits(
+        env: Env,
+        fleet_operator: Address,
+        driver_address: Address,
+        new_limits: SpendingLimits,
+    ) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+
+        check_time_windows(&new_limits.allowed_windows)?;
+        let mut driver = read_fleet_driver(&env, &fleet_operator, &driver_address)?;
+        driver.spending_limits = new_limits;
+        env.storage().persistent().set(&DataKey::Driver(driver_address.clone()), &driver);
+
+        env.events().publish(
+            (Symbol::new(&env, "limits_updated"), driver_address),
+            fleet_operator,
+        );
+        Ok(())
+    }
+
+    /// Set the fuel grade of a driver's vehicle (fleet operator only)
+    pub fn set_vehicle_fuel_grade(
+        env: Env,
+        fleet_operator: Address,
+        driver_address: Address,
+        fuel_grade: FuelGrade,
+    ) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+
+        let mut driver = read_fleet_driver(&env, &fleet_operator, &driver_address)?;
+        driver.fuel_grade = fuel_grade;
+        env.storage().persistent().set(&DataKey::Driver(driver_address.clone()), &driver);
+
+        env.events().publish(
+            (Symbol::new(&env, "fuel_grade_set"), driver_address),
+            fuel_grade,
+        );
+        Ok(())
+    }
+
+    /// Set the tank capacity of a driver's vehicle in liters, 0 if unknown
+    /// (fleet operator only)
+    pub fn set_vehicle_tank_capacity(
+        env: Env,
+        fleet_operator: Address,
+        driver_address: Address,
+        tank_capacity: i128,
+    ) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+
+        if tank_capacity < 0 {
+            return Err(RedemptionError::InvalidAmount);
+        }
+        let mut driver = read_fleet_driver(&env, &fleet_operator, &driver_address)?;
+        driver.tank_capacity = tank_capacity;
+        env.storage().persistent().set(&DataKey::Driver(driver_address.clone()), &driver);
+
+        env.events().publish(
+            (Symbol::new(&env, "tank_capacity_set"), driver_address),
+            tank_capacity,
+        );
+        Ok(())
+    }
+
+    /// Configure a fraud rule (admin only)
+    pub fn set_fraud_rule(
+        env: Env,
+        admin: Address,
+        rule: FraudRule,
+        config: RuleConfig,
+    ) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::FraudRule(rule), &config);
+
+        env.events().publish(
+            (Symbol::new(&env, "fraud_rule_set"), rule as u32),
+            (config.action, config.threshold),
+        );
+        Ok(())
+    }
+
+    /// Get a fraud rule's configuration; rules are disabled until configured
+    pub fn get_fraud_rule(env: Env, rule: FraudRule) -> RuleConfig {
+        read_rule(&env, rule)
+    }
+
+    /// Choose whether a driver's redemptions are paid from the driver's own
+    /// wallet or from the fleet operator's wallet (fleet operator only).
+    /// Fleet funding requires the operator to approve this contract on the FUEL token.
+    pub fn set_funding_source(
+        env: Env,
+        fleet_operator: Address,
+        driver_address: Address,
+        funding_source: FundingSource,
+    ) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+
+        let mut driver = read_fleet_driver(&env, &fleet_operator, &driver_address)?;
+        driver.funding_source = funding_source.clone();
+        env.storage().persistent().set(&DataKey::Driver(driver_address.clone()), &driver);
+
+        env.events().publish(
+            (Symbol::new(&env, "funding_source_updated"), driver_address),
+            funding_source,
+        );
+        Ok(())
+    }
+
+    /// Redeem fuel voucher at a station.
+    /// Transfers `amount` FUEL to the station owner in the same transaction.
+    pub fn redeem_fuel(
+        env: Env,
+        driver_address: Address,
+        station_id: BytesN<32>,
+        amount: i128,
+        gps_coords: GpsCoordinates,
+    ) -> Result<RedemptionRecord, RedemptionError> {
+        driver_address.require_auth();
+        Self::process_redemption(env, driver_address, station_id, amount, gps_coords, true)
+    }
+
+    /// Pre-issue a one-time voucher that a station can redeem without the
+    /// driver signing at the pump (driver only). `code_hash` is the SHA-256 of
+    /// a secret shared with the station, e.g. through a QR code. Driver-funded
+    /// vouchers need an allowance to this contract covering `max_amount`.
+    /// Stations commit to the secret with `commit_voucher` before revealing it,
+    /// so a secret seen on-chain can't be redeemed at another station.
+    pub fn issue_voucher(
+        env: Env,
+        driver_address: Address,
+        code_hash: BytesN<32>,
+        max_amount: i128,
+        expires_at: u64,
+        scope: VoucherScope,
+    ) -> Result<(), RedemptionError> {
+        driver_address.require_auth();
+
+        let driver = read_driver(&env, &driver_address)?;
+        if !driver.is_active {
+            return Err(RedemptionError::DriverDeactivated);
+        }
+        if max_amount <= 0 {
+            return Err(RedemptionError::InvalidAmount);
+        }
+        if expires_at <= env.ledger().timestamp() {
+            return Err(RedemptionError::VoucherExpired);
+        }
+        let key = DataKey::Voucher(code_hash.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(RedemptionError::VoucherAlreadyExists);
+        }
+
+        let voucher = Voucher {
+            code_hash: code_hash.clone(),
+            driver: driver_address.clone(),
+            max_amount,
+            expires_at,
+            scope,
+            status: VoucherStatus::Active,
+            redemption_id: BytesN::from_array(&env, &[0u8; 32]),
+        };
+        env.storage().persistent().set(&key, &voucher);
+
+        env.events().publish(
+            (Symbol::new(&env, "voucher_issued"), driver_address),
+            (code_hash, max_amount, expires_at),
+        );
+        Ok(())
+    }
+
+    /// Commit to a voucher's secret before redeeming it (station owner).
+    /// `commitment` is the SHA-256 of the secret followed by the station id.
+    /// Only verified stations the voucher allows may commit, one commitment
+    /// per station; committing again replaces the earlier commitment.
+    pub fn commit_voucher(
+        env: Env,
+        station_owner: Address,
+        station_id: BytesN<32>,
+        code_hash: BytesN<32>,
+        commitment: BytesN<32>,
+    ) -> Result<(), RedemptionError> {
+        station_owner.require_auth();
+
+        let station = read_station(&env, &station_id)?;
+        if station.owner != station_owner {
+            return Err(RedemptionError::Unauthorized);
+        }
+        if station.status != StationStatus::Verified {
+            return Err(RedemptionError::StationNotVerified);
+        }
+        let voucher: Voucher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Voucher(code_hash.clone()))
+            .ok_or(RedemptionError::VoucherNotFound)?;
+        if voucher.status != VoucherStatus::Active {
+            return Err(RedemptionError::VoucherAlreadyUsed);
+        }
+        if let VoucherScope::Station(allowed) = &voucher.scope {
+            if *allowed != station_id {
+                return Err(RedemptionError::StationNotAllowed);
+            }
+        }
+
+        let key = DataKey::VoucherCommitments(code_hash.clone());
+        let mut commitments: Vec<VoucherCommitment> =
+            env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        // A new commitment replaces the station's earlier one and goes to the back
+        if let Some(index) = commitments.iter().position(|c| c.station_id == station_id) {
+            commitments.remove(index as u32);
+        }
+        commitments.push_back(VoucherCommitment {
+            station_id: station_id.clone(),
+            commitment,
+        });
+        env.storage().persistent().set(&key, &commitments);
+
+        env.events().publish(
+            (Symbol::new(&env, "voucher_committed"), station_id),
+            code_hash,
+        );
+        Ok(())
+    }
+
+    /// Redeem a one-time voucher by revealing its secret (station owner).
+    /// The station's commitment must be the earliest one matching the secret,
+    /// so a station that sees the secret in a failed or pending transaction
+    /// can't commit after the fact and redeem it. The redemption is checked
+    /// against the driver's limits and rules like `redeem_fuel`, and the
+    /// voucher cannot be used again.
+    pub fn redeem_voucher(
+        env: Env,
+        station_owner: Address,
+        station_id: BytesN<32>,
+        secret: Bytes,
+        amount: i128,
+        gps_coords: GpsCoordinates,
+    ) -> Result<RedemptionRecord, RedemptionError> {
+        station_owner.require_auth();
+
+        if read_station(&env, &station_id)?.owner != station_owner {
+            return Err(RedemptionError::Unauthorized);
+        }
+
+        let code_hash: BytesN<32> = env.crypto().sha256(&secret).into();
+        let key = DataKey::Voucher(code_hash.clone());
+        let mut voucher: Voucher = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(RedemptionError::VoucherNotFound)?;
+
+        if voucher.status != VoucherStatus::Active {
+            return Err(RedemptionError::VoucherAlreadyUsed);
+        }
+        if env.ledger().timestamp() >= voucher.expires_at {
+            return Err(RedemptionError::VoucherExpired);
+        }
+        if let VoucherScope::Station(allowed) = &voucher.scope {
+            if *allowed != station_id {
+                return Err(RedemptionError::StationNotAllowed);
+            }
+        }
+        if amount > voucher.max_amount {
+            return Err(RedemptionError::VoucherAmountExceeded);
+        }
+
+        let commitments_key = DataKey::VoucherCommitments(code_hash.clone());
+        let commitments: Vec<VoucherCommitment> = env
+            .storage()
+            .persistent()
+            .get(&commitments_key)
+            .unwrap_or(Vec::new(&env));
+        let committed = commitments
+            .iter()
+            .find(|c| c.commitment == voucher_commitment(&env, &secret, &c.station_id));
+        if committed.map(|c| c.station_id) != Some(station_id.clone()) {
+            return Err(RedemptionError::VoucherNotCommitted);
+        }
+
+        let record = Self::process_redemption(
+            env.clone(),
+            voucher.driver.clone(),
+            station_id,
+            amount,
+            gps_coords,
+            false,
+        )?;
+
+        voucher.status = VoucherStatus::Redeemed;
+        voucher.redemption_id = record.id.clone();
+        env.storage().persistent().set(&key, &voucher);
+        env.storage().persistent().remove(&commitments_key);
+
+        env.events().publish(
+            (Symbol::new(&env, "voucher_redeemed"), voucher.driver),
+            (code_hash, record.id.clone()),
+        );
+        Ok(record)
+    }
+
+    /// Cancel an unused voucher (driver only)
+    pub fn cancel_voucher(env: Env, driver_address: Address, code_hash: BytesN<32>) -> Result<(), RedemptionError> {
+        driver_address.require_auth();
+
+        let key = DataKey::Voucher(code_hash.clone());
+        let mut voucher: Voucher = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(RedemptionError::VoucherNotFound)?;
+        if voucher.driver != driver_address {
+            return Err(RedemptionError::Unauthorized);
+        }
+        if voucher.status != VoucherStatus::Active {
+            return Err(RedemptionError::VoucherAlreadyUsed);
+        }
+
+        voucher.status = VoucherStatus::Cancelled;
+        env.storage().persistent().set(&key, &voucher);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::VoucherCommitments(code_hash.clone()));
+
+        env.events().publish(
+            (Symbol::new(&env, "voucher_cancelled"), driver_address),
+            code_hash,
+        );
+        Ok(())
+    }
+
+    /// Get a voucher by its code hash
+    pub fn get_voucher(env: Env, code_hash: BytesN<32>) -> Result<Voucher, RedemptionError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Voucher(code_hash))
+            .ok_or(RedemptionError::VoucherNotFound)
+    }
+
+    /// Get driver information
+    pub fn get_driver(env: Env, driver_address: Address) -> Result<Driver, RedemptionError> {
+        read_driver(&env, &driver_address)
+    }
+
+    /// Get station information
+    pub fn get_station(env: Env, station_id: BytesN<32>) -> Result<Station, RedemptionError> {
+        read_station(&env, &station_id)
+    }
+
+    /// Get a redemption record by id
+    pub fn get_redemption(env: Env, redemption_id: BytesN<32>) -> Result<RedemptionRecord, RedemptionError> {
+        read_redemption(&env, &redemption_id)
+    }
+
+    /// Check a redemption receipt presented off-chain: its id must match the
+    /// id derived from its fields and it must match the stored record. The
+    /// receipt's status may predate a later dispute or reversal.
+    pub fn verify_receipt(env: Env, record: RedemptionRecord) -> bool {
+        let expected =
+            redemption_id(&env, record.sequence, &record.driver, &record.station_id, record.ledger);
+        if record.id != expected {
+            return false;
+        }
+        match read_redemption(&env, &record.id) {
+            Ok(mut stored) => {
+                stored.status = record.status.clone();
+                stored == record
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Dispute a redemption the driver did not make or that was wrong (driver only).
+    /// Disputes can be opened within `DISPUTE_WINDOW_SECONDS` of the redemption.
+    pub fn open_dispute(
+        env: Env,
+        driver_address: Address,
+        redemption_id: BytesN<32>,
+        reason: String,
+    ) -> Result<(), RedemptionError> {
+        driver_address.require_auth();
+
+        let mut record = read_redemption(&env, &redemption_id)?;
+        if record.driver != driver_address {
+            return Err(RedemptionError::Unauthorized);
+        }
+        match record.status {
+            RedemptionStatus::Completed => {}
+            RedemptionStatus::Disputed => return Err(RedemptionError::DisputeAlreadyExists),
+            RedemptionStatus::Reversed => return Err(RedemptionError::RedemptionAlreadyReversed),
+        }
+        // A redemption is disputed at most once; an upheld dispute is final
+        let dispute_key = DataKey::Dispute(redemption_id.clone());
+        if env.storage().persistent().has(&dispute_key) {
+            return Err(RedemptionError::DisputeAlreadyExists);
+        }
+        if env.ledger().timestamp() > record.timestamp + DISPUTE_WINDOW_SECONDS {
+            return Err(RedemptionError::DisputeWindowClosed);
+        }
+
+        let dispute = Dispute {
+            redemption_id: redemption_id.clone(),
+            driver: driver_address.clone(),
+            station_id: record.station_id.clone(),
+            reason,
+            station_response: String::from_str(&env, ""),
+            status: DisputeStatus::Open,
+            opened_at: env.ledger().timestamp(),
+            resolved_at: 0,
+        };
+        env.storage().persistent().set(&dispute_key, &dispute);
+
+        record.status = RedemptionStatus::Disputed;
+        env.storage().persistent().set(&DataKey::Redemption(redemption_id.clone()), &record);
+
+        env.events().publish(
+            (Symbol::new(&env, "dispute_opened"), redemption_id),
+            driver_address,
+        );
+        Ok(())
+    }
+
+    /// Respond to a dispute against one of the owner's stations
+    pub fn respond_to_dispute(
+        env: Env,
+        station_owner: Address,
+        redemption_id: BytesN<32>,
+        response: String,
+    ) -> Result<(), RedemptionError> {
+        station_owner.require_auth();
+
+        let mut dispute = read_dispute(&env, &redemption_id)?;
+        let station = read_station(&env, &dispute.station_id)?;
+        if station.owner != station_owner {
+            return Err(RedemptionError::Unauthorized);
+        }
+        if dispute.status != DisputeStatus::Open {
+            return Err(RedemptionError::InvalidDisputeStatus);
+        }
+
+        dispute.station_response = response;
+        dispute.status = DisputeStatus::Responded;
+        env.storage().persistent().set(&DataKey::Dispute(redemption_id.clone()), &dispute);
+
+        env.events().publish(
+            (Symbol::new(&env, "dispute_responded"), redemption_id),
+            station_owner,
+        );
+        Ok(())
+    }
+
+    /// Resolve a dispute (the driver's fleet operator or the admin).
+    /// With `reverse` the redemption is reversed and the FUEL refunded from the
+    /// station owner, who must have approved this contract for the amount.
+    pub fn resolve_dispute(
+        env: Env,
+        resolver: Address,
+        redemption_id: BytesN<32>,
+        reverse: bool,
+    ) -> Result<(), RedemptionError> {
+        resolver.require_auth();
+
+        let mut dispute = read_dispute(&env, &redemption_id)?;
+        let mut record = read_redemption(&env, &redemption_id)?;
+        if resolver != record.fleet_operator && resolver != read_admin(&env)? {
+            return Err(RedemptionError::Unauthorized);
+        }
+        if dispute.status != DisputeStatus::Open && dispute.status != DisputeStatus::Responded {
+            return Err(RedemptionError::InvalidDisputeStatus);
+        }
+
+        if reverse {
+            let token = Self::fuel_token(&env)?;
+            let spender = env.current_contract_address();
+            if token.allowance(&record.payee, &spender) < record.amount {
+                return Err(RedemptionError::RefundNotApproved);
+            }
+            if token.balance(&record.payee) < record.amount {
+                return Err(RedemptionError::InsufficientBalance);
+            }
+            token.transfer_from(&spender, &record.payee, &record.payer, &record.amount);
+            Self::reverse_redemption_stats(&env, &mut record)?;
+            dispute.status = DisputeStatus::Reversed;
+        } else {
+            record.status = RedemptionStatus::Completed;
+            env.storage().persistent().set(&DataKey::Redemption(redemption_id.clone()), &record);
+            dispute.status = DisputeStatus::Upheld;
+        }
+        dispute.resolved_at = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::Dispute(redemption_id.clone()), &dispute);
+
+        env.events().publish(
+            (Symbol::new(&env, "dispute_resolved"), redemption_id),
+            (resolver, dispute.status),
+        );
+        Ok(())
+    }
+
+    /// Reverse an erroneous redemption, refunding the FUEL it received
+    /// (the station owner that was paid). Closes any open dispute.
+    pub fn reverse_redemption(
+        env: Env,
+        station_owner: Address,
+        redemption_id: BytesN<32>,
+        reason: String,
+    ) -> Result<(), RedemptionError> {
+        station_owner.require_auth();
+
+        let mut record = read_redemption(&env, &redemption_id)?;
+        if record.payee != station_owner {
+            return Err(RedemptionError::Unauthorized);
+        }
+        if record.status == RedemptionStatus::Reversed {
+            return Err(RedemptionError::RedemptionAlreadyReversed);
+        }
+
+        let token = Self::fuel_token(&env)?;
+        if token.balance(&station_owner) < record.amount {
+            return Err(RedemptionError::InsufficientBalance);
+        }
+        token.transfer(&station_owner, &record.payer, &record.amount);
+        Self::reverse_redemption_stats(&env, &mut record)?;
+
+        if let Ok(mut dispute) = read_dispute(&env, &redemption_id) {
+            dispute.status = DisputeStatus::Reversed;
+            dispute.resolved_at = env.ledger().timestamp();
+            env.storage().persistent().set(&DataKey::Dispute(redemption_id.clone()), &dispute);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "redemption_reversed"), redemption_id),
+            (station_owner, reason),
+        );
+        Ok(())
+    }
+
+    /// Close a finished day's settlement period for a station (station owner
+    /// or admin). The statement is frozen with the hash of its redemptions.
+    pub fn close_settlement_period(
+        env: Env,
+        caller: Address,
+        station_id: BytesN<32>,
+        day: u64,
+    ) -> Result<DailyStats, RedemptionError> {
+        caller.require_auth();
+
+        let station = read_station(&env, &station_id)?;
+        if caller != station.owner && caller != read_admin(&env)? {
+            return Err(RedemptionError::Unauthorized);
+        }
+        if day >= current_utc_day(&env) {
+            return Err(RedemptionError::SettlementPeriodOpen);
+        }
+
+        let mut stats = read_daily_stats(&env, &station_id, day);
+        if stats.closed {
+            return Err(RedemptionError::SettlementAlreadyClosed);
+        }
+        stats.closed = true;
+        stats.closed_at = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&DataKey::DailyStats(station_id.clone(), day), &stats);
+
+        env.events().publish(
+            (Symbol::new(&env, "settlement_closed"), station_id, day),
+            (stats.total_amount, stats.reversed_amount, stats.redemptions_hash.clone()),
+        );
+        Ok(stats)
+    }
+
+    /// Get a station's aggregates for a UTC day
+    pub fn get_daily_stats(env: Env, station_id: BytesN<32>, day: u64) -> DailyStats {
+        read_daily_stats(&env, &station_id, day)
+    }
+
+    /// Get a station's daily statements for days `from_day..=to_day`, at most
+    /// `MAX_PAGE_SIZE` days per call
+    pub fn get_settlement_statements(
+        env: Env,
+        station_id: BytesN<32>,
+        from_day: u64,
+        to_day: u64,
+    ) -> Vec<DailyStats> {
+        let to_day = to_day.min(from_day.saturating_add(MAX_PAGE_SIZE as u64 - 1));
+        let mut statements = Vec::new(&env);
+        for day in from_day..=to_day {
+            statements.push_back(read_daily_stats(&env, &station_id, day));
+        }
+        statements
+    }
+
+    /// Get the dispute for a redemption
+    pub fn get_dispute(env: Env, redemption_id: BytesN<32>) -> Result<Dispute, RedemptionError> {
+        read_dispute(&env, &redemption_id)
+    }
+
+    /// Get a driver's redemptions, oldest first, optionally within a timestamp range
+    pub fn get_driver_redemptions(
+        env: Env,
+        driver_address: Address,
+        cursor: u32,
+        limit: u32,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> RedemptionPage {
+        read_index_page(&env, RedemptionIndex::Driver(driver_address), cursor, limit, from, to)
+    }
+
+    /// Get a station's redemptions, oldest first, optionally within a timestamp range
+    pub fn get_station_redemptions(
+        env: Env,
+        station_id: BytesN<32>,
+        cursor: u32,
+        limit: u32,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> RedemptionPage {
+        read_index_page(&env, RedemptionIndex::Station(station_id), cursor, limit, from, to)
+    }
+
+    /// Get all redemptions by a fleet's drivers, oldest first, optionally within a timestamp range
+    pub fn get_fleet_redemptions(
+        env: Env,
+        fleet_operator: Address,
+        cursor: u32,
+        limit: u32,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> RedemptionPage {
+        read_index_page(&env, RedemptionIndex::Fleet(fleet_operator), cursor, limit, from, to)
+    }
+
+    /// Get the ids of all stations owned by an address
+    pub fn get_stations_by_owner(env: Env, owner: Address) -> Vec<BytesN<32>> {
+        read_owner_stations(&env, &owner)
+    }
+
+    /// Get driver's remaining daily limit
+    pub fn get_remaining_daily_limit(env: Env, driver_address: Address) -> Result<i128, RedemptionError> {
+        Ok(Self::get_remaining_limits(env, driver_address)?.daily)
+    }
+
+    /// Get driver's remaining daily, weekly and monthly limits
+    pub fn get_remaining_limits(env: Env, driver_address: Address) -> Result<RemainingLimits, RedemptionError> {
+        let mut driver = read_driver(&env, &driver_address)?;
+        let windows = current_windows(&env, &driver.fleet_operator);
+        roll_spending_windows(&mut driver, &windows);
+
+        let limits = &driver.spending_limits;
+        Ok(RemainingLimits {
+            daily: (limits.daily_limit - driver.daily_spent).max(0),
+            weekly: (limits.weekly_limit - driver.weekly_spent).max(0),
+            monthly: (limits.monthly_limit - driver.monthly_spent).max(0),
+        })
+    }
+
+    /// Set the UTC offset in seconds that aligns the fleet's spending windows
+    /// to local midnight (fleet operator only)
+    pub fn set_fleet_utc_offset(
+        env: Env,
+        fleet_operator: Address,
+        offset_seconds: i32,
+    ) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+
+        if !(MIN_UTC_OFFSET..=MAX_UTC_OFFSET).contains(&offset_seconds) {
+            return Err(RedemptionError::InvalidUtcOffset);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::FleetUtcOffset(fleet_operator.clone()), &offset_seconds);
+
+        env.events().publish(
+            (Symbol::new(&env, "fleet_utc_offset_set"), fleet_operator),
+            offset_seconds,
+        );
+        Ok(())
+    }
+
+    /// Set the fleet's default redemption time windows, used for drivers
+    /// without their own windows; empty allows any time (fleet operator only)
+    pub fn set_fleet_time_windows(
+        env: Env,
+        fleet_operator: Address,
+        windows: Vec<TimeWindow>,
+    ) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+
+        check_time_windows(&windows)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::FleetTimeWindows(fleet_operator.clone()), &windows);
+
+        env.events().publish(
+            (Symbol::new(&env, "fleet_time_windows_set"), fleet_operator),
+            windows.len(),
+        );
+        Ok(())
+    }
+
+    /// Get the fleet's default redemption time windows
+    pub fn get_fleet_time_windows(env: Env, fleet_operator: Address) -> Vec<TimeWindow> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FleetTimeWindows(fleet_operator))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the fleet's UTC offset in seconds
+    pub fn get_fleet_utc_offset(env: Env, fleet_operator: Address) -> i32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FleetUtcOffset(fleet_operator))
+            .unwrap_or(0)
+    }
+
+    /// Deactivate a driver (fleet operator only)
+    pub fn deactivate_driver(env: Env, fleet_operator: Address, driver_address: Address) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+
+        let mut driver = read_fleet_driver(&env, &fleet_operator, &driver_address)?;
+        if driver.is_active {
+            let mut stats = read_fleet_stats(&env, &fleet_operator);
+            stats.active_drivers -= 1;
+            write_fleet_stats(&env, &fleet_operator, &stats);
+        }
+        driver.is_active = false;
+        env.storage().persistent().set(&DataKey::Driver(driver_address.clone()), &driver);
+
+        env.events().publish(
+            (Symbol::new(&env, "driver_deactivated"), driver_address),
+            fleet_operator,
+        );
+        Ok(())
+    }
+
+    /// Reactivate a driver (fleet operator only)
+    pub fn reactivate_driver(env: Env, fleet_operator: Address, driver_address: Address) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+
+        let mut driver = read_fleet_driver(&env, &fleet_operator, &driver_address)?;
+        if !driver.is_active {
+            let mut stats = read_fleet_stats(&env, &fleet_operator);
+            stats.active_drivers += 1;
+            write_fleet_stats(&env, &fleet_operator, &stats);
+        }
+        driver.is_active = true;
+        env.storage().persistent().set(&DataKey::Driver(driver_address.clone()), &driver);
+
+        env.events().publish(
+            (Symbol::new(&env, "driver_reactivated"), driver_address),
+            fleet_operator,
+        );
+        Ok(())
+    }
+
+    /// Remove a driver from the fleet and delete their registration (fleet operator only)
+    pub fn remove_driver(env: Env, fleet_operator: Address, driver_address: Address) -> Result<(), RedemptionError> {
+        fleet_operator.require_auth();
+
+        let driver = read_fleet_driver(&env, &fleet_operator, &driver_address)?;
+        env.storage().persistent().remove(&DataKey::Driver(driver_address.clone()));
+        remove_from_roster(&env, &fleet_operator, &driver);
+
+        env.events().publish(
+            (Symbol::new(&env, "driver_removed"), driver_address),
+            fleet_operator,
+        );
+        Ok(())
+    }
+
+    /// List a fleet's drivers, starting at position `cursor` in the roster.
+    /// At most `MAX_PAGE_SIZE` drivers are returned per call. Removing a
+    /// driver moves the last driver into its position.
+    pub fn get_fleet_drivers(env: Env, fleet_operator: Address, cursor: u32, limit: u32) -> Vec<Driver> {
+        let total = read_fleet_stats(&env, &fleet_operator).total_drivers;
+        let end = cursor.saturating_add(limit.min(MAX_PAGE_SIZE)).min(total);
+
+        let mut drivers = Vec::new(&env);
+        for position in cursor..end {
+            let driver_address: Option<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::FleetDriver(fleet_operator.clone(), position));
+            if let Some(Ok(driver)) = driver_address.map(|address| read_driver(&env, &address)) {
+                drivers.push_back(driver);
+            }
+        }
+        drivers
+    }
+
+    /// Get driver counts and current spend totals for a fleet. Spend stays
+    /// with the fleet it was made under when a driver later leaves.
+    pub fn get_fleet_summary(env: Env, fleet_operator: Address) -> FleetSummary {
+        let mut stats = read_fleet_stats(&env, &fleet_operator);
+        roll_fleet_windows(&mut stats, &current_windows(&env, &fleet_operator));
+        FleetSummary {
+            total_drivers: stats.total_drivers,
+            active_drivers: stats.active_drivers,
+            deactivated_drivers: stats.total_drivers - stats.active_drivers,
+            daily_spent: stats.daily_spent,
+            weekly_spent: stats.weekly_spent,
+            monthly_spent: stats.monthly_spent,
+        }
+    }
+
+    /// Set the price of a fuel grade at a station from `effective_from`
+    /// (a ledger timestamp, now or later), adding the grade if it was not sold.
+    /// The price must respect the grade's bounds and maximum change per update.
+    pub fn update_fuel_price(
+        env: Env,
+        station_owner: Address,
+        station_id: BytesN<32>,
+        fuel_grade: FuelGrade,
+        new_price: i128,
+        effective_from: u64,
+    ) -> Result<(), RedemptionError> {
+        station_owner.require_auth();
+
+        let key = DataKey::Station(station_id.clone());
+        let mut station = read_station(&env, &station_id)?;
+
+        if station.owner != station_owner {
+            return Err(RedemptionError::Unauthorized);
+        }
+
+        // A re-added grade is compared with the price it was last sold at
+        let history = read_price_history(&env, &station_id, fuel_grade);
+        check_price(&env, fuel_grade, new_price, last_recorded_price(&history))?;
+
+        // Price points must be in effective order and cannot be backdated
+        let earliest = history
+            .last()
+            .map_or(0, |point| point.effective_from)
+            .max(env.ledger().timestamp());
+        if effective_from < earliest {
+            return Err(RedemptionError::InvalidEffectiveTime);
+        }
+
+        station.fuel_prices.set(fuel_grade, new_price);
+        env.storage().persistent().set(&key, &station);
+        append_price_point(
+            &env,
+            &station_id,
+            fuel_grade,
+            PricePoint {
+                price: new_price,
+                effective_from,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "price_updated"), station_id),
+            (fuel_grade, new_price, effective_from),
+        );
+        Ok(())
+    }
+
+    /// Set the price bounds for a fuel grade (admin only)
+    pub fn set_price_bounds(
+        env: Env,
+        admin: Address,
+        fuel_grade: FuelGrade,
+        bounds: PriceBounds,
+    ) -> Result<(), RedemptionError> {
+        require_admin(&env, &admin)?;
+
+        if bounds.min_price <= 0 || bounds.min_price > bounds.max_price {
+            return Err(RedemptionError::InvalidPrice);
+        }
+        env.storage().instance().set(&DataKey::PriceBounds(fuel_grade), &bounds);
+
+        env.events().publish(
+            (Symbol::new(&env, "price_bounds_set"), fuel_grade),
+            (bounds.min_price, bounds.max_price, bounds.max_change_bps),
+        );
+        Ok(())
+    }
+
+    /// Get the price bounds for a fuel grade, if configured
+    pub fn get_price_bounds(env: Env, fuel_grade: FuelGrade) -> Option<PriceBounds> {
+        env.storage().instance().get(&DataKey::PriceBounds(fuel_grade))
+    }
+
+    /// Get a station's price history for a grade, oldest first
+    pub fn get_price_history(env: Env, station_id: BytesN<32>, fuel_grade: FuelGrade) -> Vec<PricePoint> {
+        read_price_history(&env, &station_id, fuel_grade)
+    }
+
+    /// Stop selling a fuel grade at a station
+    pub fn remove_fuel_grade(
+        env: Env,
+        station_owner: Address,
+        station_id: BytesN<32>,
+        fuel_grade: FuelGrade,
+    ) -> Result<(), RedemptionError> {
+        station_owner.require_auth();
+
+        let key = DataKey::Station(station_id.clone());
+        let mut station = read_station(&env, &station_id)?;
+
+        if station.owner != station_owner {
+            return Err(RedemptionError::Unauthorized);
+        }
+
+        station.fuel_prices.remove(fuel_grade);
+        env.storage().persistent().set(&key, &station);
+        withdraw_price(&env, &station_id, fuel_grade);
+
+        env.events().publish(
+            (Symbol::new(&env, "fuel_grade_removed"), station_id),
+            fuel_grade,
+        );
+        Ok(())
+    }
+
+    /// Get total redemption count
+    pub fn get_redemption_count(env: Env) -> u64 {
+        env.storage().instance().get(&DataKey::RedemptionCount).unwrap_or(0)
+    }
+}
+
+impl VoucherRedemption {
+    /// Validate and settle a redemption for a driver. `driver_signed` is false
+    /// for voucher redemptions, where a driver-funded payment is pulled through
+    /// the driver's allowance to this contract.
+    fn process_redemption(
+        env: Env,
+        driver_address: Address,
+        station_id: BytesN<32>,
+        amount: i128,
+        gps_coords: GpsCoordinates,
+        driver_signed: bool,
+    ) -> Result<RedemptionRecord, RedemptionError> {
+        if amount <= 0 {
+            return Err(RedemptionError::InvalidAmount);
+        }
+
+        // Get driver info
+        let driver_key = DataKey::Driver(driver_address.clone());
+        let mut driver = read_driver(&env, &driver_address)?;
+
+        if !driver.is_active {
+            return Err(RedemptionError::DriverDeactivated);
+        }
+
+        // Get station info
+        let station_key = DataKey::Station(station_id.clone());
+        let mut station = read_station(&env, &station_id)?;
+
+        match station.status {
+            StationStatus::Verified => {}
+            StationStatus::Suspended | StationStatus::Revoked => {
+                return Err(RedemptionError::StationInactive);
+            }
+            StationStatus::Pending | StationStatus::Rejected => {
+                return Err(RedemptionError::StationNotVerified);
+            }
+        }
+
+        // Check geofence
+        check_station_location(&env, &station, &gps_coords)?;
 
         // Check allowed stations (if restricted)
         if !driver.spending_limits.allowed_stations.is_empty() {
@@ -319,62 +2560,97 @@ impl VoucherRedemption {
                 }
             }
             if !allowed {
-                panic!("Station not in allowed list");
+                return Err(RedemptionError::StationNotAllowed);
             }
         }
 
-        // Reset daily/weekly limits if needed
-        let current_ledger = env.ledger().sequence() as u64;
-        if current_ledger - driver.last_daily_reset >= LEDGERS_PER_DAY {
-            driver.daily_spent = 0;
-            driver.last_daily_reset = current_ledger;
-        }
-        if current_ledger - driver.last_weekly_reset >= LEDGERS_PER_WEEK {
-            driver.weekly_spent = 0;
-            driver.last_weekly_reset = current_ledger;
-        }
+        // Reset spend counters when a calendar window has rolled over
+        let windows = current_windows(&env, &driver.fleet_operator);
+        roll_spending_windows(&mut driver, &windows);
 
         // Check spending limits
         if amount > driver.spending_limits.max_per_transaction {
-            panic!("Transaction limit exceeded");
+            return Err(RedemptionError::TransactionLimitExceeded);
         }
         if driver.daily_spent + amount > driver.spending_limits.daily_limit {
-            panic!("Daily limit exceeded");
+            return Err(RedemptionError::DailyLimitExceeded);
         }
         if driver.weekly_spent + amount > driver.spending_limits.weekly_limit {
-            panic!("Weekly limit exceeded");
+            return Err(RedemptionError::WeeklyLimitExceeded);
         }
+        if driver.monthly_spent + amount > driver.spending_limits.monthly_limit {
+            return Err(RedemptionError::MonthlyLimitExceeded);
+        }
+        check_redemption_time(&env, &driver)?;
+
+        // Calculate liters at the vehicle grade's price in effect now
+        let price_per_liter =
+            effective_price(&env, &station, driver.fuel_grade, env.ledger().timestamp())?;
+        let liters = (amount * 10_000_000) / price_per_liter; // 7 decimal precision
 
-        // Calculate liters
-        let liters = (amount * 10_000_000) / station.fuel_price_per_liter; // 7 decimal precision
+        let fraud_flags = check_fraud_rules(&env, &driver, liters)?;
 
         // Generate redemption ID
         let redemption_count: u64 = env.storage().instance().get(&DataKey::RedemptionCount).unwrap_or(0);
         let new_count = redemption_count + 1;
-        
-        // Create redemption ID from hash of relevant data
-        let redemption_id = env.crypto().sha256(
-            &soroban_sdk::Bytes::from_slice(&env, &new_count.to_be_bytes())
-        );
+        let ledger = env.ledger().sequence();
+        let redemption_id = redemption_id(&env, new_count, &driver_address, &station_id, ledger);
+
+        let payer = match driver.funding_source {
+            FundingSource::DriverWallet => driver_address.clone(),
+            FundingSource::FleetWallet => driver.fleet_operator.clone(),
+        };
 
         // Create redemption record
         let record = RedemptionRecord {
             id: redemption_id,
+            sequence: new_count,
+            ledger,
             driver: driver_address.clone(),
+            fleet_operator: driver.fleet_operator.clone(),
             station_id: station_id.clone(),
+            fuel_grade: driver.fuel_grade,
+            price_per_liter,
             amount,
             liters,
             gps_coords,
             timestamp: env.ledger().timestamp(),
             vehicle_id: driver.vehicle_id.clone(),
+            payer: payer.clone(),
+            payee: station.owner.clone(),
+            status: RedemptionStatus::Completed,
         };
 
+        // Pay the station owner in FUEL; any failure reverts the whole redemption
+        let token = Self::fuel_token(&env)?;
+        if token.balance(&payer) < amount {
+            return Err(RedemptionError::InsufficientBalance);
+        }
+        if driver_signed && driver.funding_source == FundingSource::DriverWallet {
+            token.transfer(&driver_address, &station.owner, &amount);
+        } else {
+            let spender = env.current_contract_address();
+            if token.allowance(&payer, &spender) < amount {
+                return Err(RedemptionError::PaymentNotApproved);
+            }
+            token.transfer_from(&spender, &payer, &station.owner, &amount);
+        }
+
         // Update driver stats
         driver.daily_spent += amount;
         driver.weekly_spent += amount;
+        driver.monthly_spent += amount;
         driver.total_redemptions += 1;
         env.storage().persistent().set(&driver_key, &driver);
 
+        // Update fleet spend totals
+        let mut fleet_stats = read_fleet_stats(&env, &driver.fleet_operator);
+        roll_fleet_windows(&mut fleet_stats, &windows);
+        fleet_stats.daily_spent += amount;
+        fleet_stats.weekly_spent += amount;
+        fleet_stats.monthly_spent += amount;
+        write_fleet_stats(&env, &driver.fleet_operator, &fleet_stats);
+
         // Update station stats
         station.total_redemptions += 1;
         env.storage().persistent().set(&station_key, &station);
@@ -385,105 +2661,132 @@ impl VoucherRedemption {
         // Store last redemption for driver
         env.storage().persistent().set(&DataKey::LastRedemption(driver_address.clone()), &record);
 
+        // Store the full record and index it for history queries
+        env.storage().persistent().set(&DataKey::Redemption(record.id.clone()), &record);
+        append_to_index(&env, RedemptionIndex::Driver(driver_address.clone()), &record.id);
+        append_to_index(&env, RedemptionIndex::Station(station_id.clone()), &record.id);
+        append_to_index(&env, RedemptionIndex::Fleet(driver.fleet_operator.clone()), &record.id);
+        record_daily_stats(&env, &record, false);
+
+        // Report to credit scoring; a scoring failure must not block fuel purchases
+        if let Some(credit_score) = env.storage().instance().get::<DataKey, Address>(&DataKey::CreditScore) {
+            let recorded = CreditScoreClient::new(&env, &credit_score).try_record_transaction(
+                &env.current_contract_address(),
+                &driver_address,
+                &amount,
+                &station_id,
+                &env.ledger().timestamp(),
+            );
+            if recorded.is_err() {
+                env.events().publish(
+                    (Symbol::new(&env, "credit_record_failed"), driver_address.clone()),
+                    credit_score,
+                );
+            }
+        }
+
+        for rule in fraud_flags.iter() {
+            env.events().publish(
+                (Symbol::new(&env, "fraud_flag"), driver_address.clone(), rule as u32),
+                record.id.clone(),
+            );
+        }
+
         // Emit redemption event for credit scoring
         env.events().publish(
             (Symbol::new(&env, "fuel_redeemed"), driver_address, station_id),
             (amount, liters, env.ledger().timestamp()),
         );
 
-        record
-    }
-
-    /// Get driver information
-    pub fn get_driver(env: Env, driver_address: Address) -> Driver {
-        env.storage().persistent().get(&DataKey::Driver(driver_address))
-            .expect("Driver not found")
-    }
-
-    /// Get station information
-    pub fn get_station(env: Env, station_id: BytesN<32>) -> Station {
-        env.storage().persistent().get(&DataKey::Station(station_id))
-            .expect("Station not found")
+        Ok(record)
     }
 
-    /// Get driver's remaining daily limit
-    pub fn get_remaining_daily_limit(env: Env, driver_address: Address) -> i128 {
-        let driver: Driver = env.storage().persistent().get(&DataKey::Driver(driver_address))
-            .expect("Driver not found");
-        
-        let current_ledger = env.ledger().sequence() as u64;
-        if current_ledger - driver.last_daily_reset >= LEDGERS_PER_DAY {
-            driver.spending_limits.daily_limit
-        } else {
-            driver.spending_limits.daily_limit - driver.daily_spent
+    /// Move a station from `from` to `to`, recording the reason
+    fn transition_station(
+        env: &Env,
+        station_id: &BytesN<32>,
+        from: StationStatus,
+        to: StationStatus,
+        reason: String,
+    ) -> Result<(), RedemptionError> {
+        let mut station = read_station(env, station_id)?;
+        if station.status != from {
+            return Err(RedemptionError::InvalidStationStatus);
         }
-    }
-
-    /// Deactivate a driver (fleet operator only)
-    pub fn deactivate_driver(env: Env, fleet_operator: Address, driver_address: Address) {
-        fleet_operator.require_auth();
 
-        let key = DataKey::Driver(driver_address.clone());
-        let mut driver: Driver = env.storage().persistent().get(&key)
-            .expect("Driver not registered");
-
-        if driver.fleet_operator != fleet_operator {
-            panic!("Unauthorized");
+        if to == StationStatus::Verified && station.verified_at == 0 {
+            station.verified_at = env.ledger().timestamp();
         }
-
-        driver.is_active = false;
-        env.storage().persistent().set(&key, &driver);
+        station.status = to.clone();
+        station.status_reason = reason.clone();
+        env.storage().persistent().set(&DataKey::Station(station_id.clone()), &station);
 
         env.events().publish(
-            (Symbol::new(&env, "driver_deactivated"), driver_address),
-            fleet_operator,
+            (Symbol::new(env, "station_status_changed"), station_id.clone()),
+            (to, reason),
         );
+        Ok(())
     }
 
-    /// Reactivate a driver (fleet operator only)
-    pub fn reactivate_driver(env: Env, fleet_operator: Address, driver_address: Address) {
-        fleet_operator.require_auth();
-
-        let key = DataKey::Driver(driver_address.clone());
-        let mut driver: Driver = env.storage().persistent().get(&key)
-            .expect("Driver not registered");
-
-        if driver.fleet_operator != fleet_operator {
-            panic!("Unauthorized");
-        }
-
-        driver.is_active = true;
-        env.storage().persistent().set(&key, &driver);
-
-        env.events().publish(
-            (Symbol::new(&env, "driver_reactivated"), driver_address),
-            fleet_operator,
-        );
+    fn fuel_token(env: &Env) -> Result<token::Client<'_>, RedemptionError> {
+        let fuel_token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::FuelToken)
+            .ok_or(RedemptionError::NotInitialized)?;
+        Ok(token::Client::new(env, &fuel_token))
     }
 
-    /// Update station fuel price
-    pub fn update_fuel_price(env: Env, station_owner: Address, station_id: BytesN<32>, new_price: i128) {
-        station_owner.require_auth();
-
-        let key = DataKey::Station(station_id.clone());
-        let mut station: Station = env.storage().persistent().get(&key)
-            .expect("Station not found");
-
-        if station.owner != station_owner {
-            panic!("Unauthorized");
+    /// Mark a redemption reversed, returning its amount to the fleet's totals
+    /// and the driver's limits if it falls within the current windows and the
+    /// driver is still in that fleet, and undo the counters
+    fn reverse_redemption_stats(env: &Env, record: &mut RedemptionRecord) -> Result<(), RedemptionError> {
+        record.status = RedemptionStatus::Reversed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Redemption(record.id.clone()), record);
+        record_daily_stats(env, record, true);
+
+        let now = current_windows(env, &record.fleet_operator);
+        let then = windows_at(env, &record.fleet_operator, record.timestamp);
+        let mut fleet_stats = read_fleet_stats(env, &record.fleet_operator);
+        roll_fleet_windows(&mut fleet_stats, &now);
+        if then.day == now.day {
+            fleet_stats.daily_spent = (fleet_stats.daily_spent - record.amount).max(0);
+        }
+        if then.week == now.week {
+            fleet_stats.weekly_spent = (fleet_stats.weekly_spent - record.amount).max(0);
+        }
+        if then.month == now.month {
+            fleet_stats.monthly_spent = (fleet_stats.monthly_spent - record.amount).max(0);
+        }
+        write_fleet_stats(env, &record.fleet_operator, &fleet_stats);
+
+        if let Ok(mut driver) = read_driver(env, &record.driver) {
+            // Spend counters started fresh if the driver has since changed fleet
+            if driver.fleet_operator == record.fleet_operator {
+                roll_spending_windows(&mut driver, &now);
+                if then.day == now.day {
+                    driver.daily_spent = (driver.daily_spent - record.amount).max(0);
+                }
+                if then.week == now.week {
+                    driver.weekly_spent = (driver.weekly_spent - record.amount).max(0);
+                }
+                if then.month == now.month {
+                    driver.monthly_spent = (driver.monthly_spent - record.amount).max(0);
+                }
+            }
+            driver.total_redemptions = driver.total_redemptions.saturating_sub(1);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Driver(record.driver.clone()), &driver);
         }
 
-        station.fuel_price_per_liter = new_price;
-        env.storage().persistent().set(&key, &station);
-
-        env.events().publish(
-            (Symbol::new(&env, "price_updated"), station_id),
-            new_price,
-        );
-    }
-
-    /// Get total redemption count
-    pub fn get_redemption_count(env: Env) -> u64 {
-        env.storage().instance().get(&DataKey::RedemptionCount).unwrap_or(0)
+        let mut station = read_station(env, &record.station_id)?;
+        station.total_redemptions = station.total_redemptions.saturating_sub(1);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Station(record.station_id.clone()), &station);
+        Ok(())
     }
 }
@@ -0,0 +1,2292 @@
+//! Tests for the voucher redemption contract
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    map, vec, Address, Bytes, BytesN, Env, Map, String, Symbol, TryFromVal,
+};
+
+use crate::{
+    DisputeStatus, FraudRule, FuelGrade, FundingSource, Geofence, GpsCoordinates, PriceBounds,
+    RedemptionError, RedemptionStatus, RuleAction, RuleConfig, SpendingLimits, StationStatus,
+    StationZone, TimeWindow, VoucherRedemption, VoucherRedemptionClient, VoucherScope,
+    VoucherStatus,
+};
+use credit_score::{CreditScore, CreditScoreClient};
+use fuel_token::{FuelToken, FuelTokenClient};
+use geofencing::{GeoPoint, Geofencing, GeofencingClient, ZoneType};
+
+/// One FUEL (or one liter) at the token's 7 decimals
+const FUEL: i128 = 10_000_000;
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    token: FuelTokenClient<'a>,
+    redemption: VoucherRedemptionClient<'a>,
+    fleet: Address,
+    driver: Address,
+    station_owner: Address,
+    station_id: BytesN<32>,
+}
+
+fn station_location() -> GpsCoordinates {
+    // Nairobi CBD
+    GpsCoordinates {
+        latitude: -1_286_389,
+        longitude: 36_817_222,
+    }
+}
+
+fn default_limits(env: &Env) -> SpendingLimits {
+    SpendingLimits {
+        max_per_transaction: 500 * FUEL,
+        daily_limit: 1_000 * FUEL,
+        weekly_limit: 5_000 * FUEL,
+        monthly_limit: 20_000 * FUEL,
+        allowed_stations: vec![env],
+        allowed_windows: vec![env],
+    }
+}
+
+fn default_prices(env: &Env) -> Map<FuelGrade, i128> {
+    map![
+        env,
+        (FuelGrade::Petrol, 180 * FUEL),
+        (FuelGrade::Diesel, 150 * FUEL)
+    ]
+}
+
+fn submit_station(
+    env: &Env,
+    redemption: &VoucherRedemptionClient,
+    owner: &Address,
+    station_id: &BytesN<32>,
+    name: &str,
+) {
+    redemption.submit_station_application(
+        owner,
+        station_id,
+        &String::from_str(env, name),
+        &Geofence {
+            center: station_location(),
+            radius_meters: 200,
+        },
+        &default_prices(env),
+        &vec![env, BytesN::from_array(env, &[9u8; 32])],
+    );
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_id = env.register_contract(None, FuelToken);
+    let token = FuelTokenClient::new(&env, &token_id);
+    token.initialize(
+        &admin,
+        &7u32,
+        &String::from_str(&env, "FuelAnchor Token"),
+        &String::from_str(&env, "FUEL"),
+    );
+
+    let redemption_id = env.register_contract(None, VoucherRedemption);
+    let redemption = VoucherRedemptionClient::new(&env, &redemption_id);
+    redemption.initialize(&admin, &token_id);
+
+    let fleet = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let station_owner = Address::generate(&env);
+    let station_id = BytesN::from_array(&env, &[1u8; 32]);
+
+    submit_station(&env, &redemption, &station_owner, &station_id, "Kenol Moi Avenue");
+    redemption.approve_station(&admin, &station_id);
+    redemption.register_driver(
+        &fleet,
+        &driver,
+        &String::from_str(&env, "KDA 123A"),
+        &FuelGrade::Diesel,
+        &(60 * FUEL),
+        &default_limits(&env),
+    );
+
+    Setup {
+        env,
+        admin,
+        token,
+        redemption,
+        fleet,
+        driver,
+        station_owner,
+        station_id,
+    }
+}
+
+#[test]
+fn test_redeem_fuel_pays_station_from_driver() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+
+    assert_eq!(record.liters, 2 * FUEL);
+    assert_eq!(s.token.balance(&s.driver), 700 * FUEL);
+    assert_eq!(s.token.balance(&s.station_owner), 300 * FUEL);
+    assert_eq!(s.redemption.get_driver(&s.driver).daily_spent, 300 * FUEL);
+}
+
+#[test]
+fn test_redeem_fuel_pays_station_from_fleet_allowance() {
+    let s = setup();
+    s.token.mint(&s.fleet, &(1_000 * FUEL));
+    let expiration_ledger = s.env.ledger().sequence() + 1000;
+    s.token
+        .approve(&s.fleet, &s.redemption.address, &(500 * FUEL), &expiration_ledger);
+    s.redemption
+        .set_funding_source(&s.fleet, &s.driver, &FundingSource::FleetWallet);
+
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+
+    assert_eq!(s.token.balance(&s.fleet), 700 * FUEL);
+    assert_eq!(s.token.balance(&s.station_owner), 300 * FUEL);
+    assert_eq!(
+        s.token.allowance(&s.fleet, &s.redemption.address),
+        200 * FUEL
+    );
+}
+
+#[test]
+fn test_redeem_fuel_is_atomic_with_payment() {
+    let s = setup();
+    s.token.mint(&s.driver, &(100 * FUEL));
+
+    let result = s
+        .redemption
+        .try_redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+
+    assert!(result.is_err());
+    assert_eq!(s.token.balance(&s.station_owner), 0);
+    assert_eq!(s.redemption.get_driver(&s.driver).daily_spent, 0);
+    assert_eq!(s.redemption.get_redemption_count(), 0);
+}
+
+#[test]
+fn test_error_already_initialized() {
+    let s = setup();
+    let admin = Address::generate(&s.env);
+
+    assert_eq!(
+        s.redemption.try_initialize(&admin, &s.token.address),
+        Err(Ok(RedemptionError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_error_driver_not_registered() {
+    let s = setup();
+    let stranger = Address::generate(&s.env);
+
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&stranger, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::DriverNotRegistered))
+    );
+    assert_eq!(
+        s.redemption.try_get_driver(&stranger),
+        Err(Ok(RedemptionError::DriverNotRegistered))
+    );
+}
+
+#[test]
+fn test_error_driver_deactivated() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption.deactivate_driver(&s.fleet, &s.driver);
+
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::DriverDeactivated))
+    );
+}
+
+#[test]
+fn test_error_unauthorized_fleet_operator() {
+    let s = setup();
+    let other_fleet = Address::generate(&s.env);
+
+    assert_eq!(
+        s.redemption
+            .try_update_spending_limits(&other_fleet, &s.driver, &default_limits(&s.env)),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+    assert_eq!(
+        s.redemption.try_deactivate_driver(&other_fleet, &s.driver),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_error_station_not_found() {
+    let s = setup();
+    let unknown = BytesN::from_array(&s.env, &[9u8; 32]);
+
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &unknown, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::StationNotFound))
+    );
+}
+
+#[test]
+fn test_error_out_of_geofence() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let far_away = GpsCoordinates {
+        latitude: -4_043_500,
+        longitude: 39_668_200,
+    };
+
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &far_away),
+        Err(Ok(RedemptionError::OutOfGeofence))
+    );
+}
+
+#[test]
+fn test_error_station_not_in_allowed_list() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let mut limits = default_limits(&s.env);
+    limits
+        .allowed_stations
+        .push_back(BytesN::from_array(&s.env, &[2u8; 32]));
+    s.redemption
+        .update_spending_limits(&s.fleet, &s.driver, &limits);
+
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::StationNotAllowed))
+    );
+}
+
+#[test]
+fn test_error_spending_limits() {
+    let s = setup();
+    s.token.mint(&s.driver, &(10_000 * FUEL));
+    let mut limits = default_limits(&s.env);
+    limits.daily_limit = 400 * FUEL;
+    limits.weekly_limit = 300 * FUEL;
+    s.redemption
+        .update_spending_limits(&s.fleet, &s.driver, &limits);
+
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(600 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::TransactionLimitExceeded))
+    );
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(350 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::WeeklyLimitExceeded))
+    );
+
+    limits.weekly_limit = 5_000 * FUEL;
+    s.redemption
+        .update_spending_limits(&s.fleet, &s.driver, &limits);
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(200 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::DailyLimitExceeded))
+    );
+}
+
+#[test]
+fn test_error_invalid_amount_and_insufficient_balance() {
+    let s = setup();
+
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &0, &station_location()),
+        Err(Ok(RedemptionError::InvalidAmount))
+    );
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_error_unauthorized_price_update() {
+    let s = setup();
+    let stranger = Address::generate(&s.env);
+
+    assert_eq!(
+        s.redemption
+            .try_update_fuel_price(&stranger, &s.station_id, &FuelGrade::Diesel, &(160 * FUEL), &0),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_redemption_recorded_in_credit_score() {
+    let s = setup();
+    let admin = Address::generate(&s.env);
+    let credit_id = s.env.register_contract(None, CreditScore);
+    let credit = CreditScoreClient::new(&s.env, &credit_id);
+    credit.initialize(&admin);
+    credit.authorize_recorder(&admin, &s.redemption.address);
+    s.redemption.set_credit_score_contract(&s.admin, &credit_id);
+
+    let second_station = BytesN::from_array(&s.env, &[2u8; 32]);
+    submit_station(&s.env, &s.redemption, &s.station_owner, &second_station, "Rubis Westlands");
+    s.redemption.approve_station(&s.admin, &second_station);
+
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location());
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location());
+    s.redemption
+        .redeem_fuel(&s.driver, &second_station, &(100 * FUEL), &station_location());
+
+    let profile = credit.get_profile(&s.driver);
+    assert_eq!(profile.total_transactions, 3);
+    assert_eq!(profile.total_amount, 300 * FUEL);
+    assert_eq!(profile.unique_stations, 2);
+}
+
+#[test]
+fn test_unauthorized_recorder_does_not_block_redemption() {
+    let s = setup();
+    let admin = Address::generate(&s.env);
+    let credit_id = s.env.register_contract(None, CreditScore);
+    let credit = CreditScoreClient::new(&s.env, &credit_id);
+    credit.initialize(&admin);
+    s.redemption.set_credit_score_contract(&s.admin, &credit_id);
+
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location());
+
+    assert_eq!(s.token.balance(&s.station_owner), 100 * FUEL);
+    assert!(credit.try_get_profile(&s.driver).is_err());
+
+    // Direct calls from unregistered recorders are rejected
+    let rogue = Address::generate(&s.env);
+    assert!(credit
+        .try_record_transaction(&rogue, &s.driver, &1, &s.station_id, &0)
+        .is_err());
+}
+
+fn setup_geofencing<'a>(s: &Setup) -> GeofencingClient<'a> {
+    let geofencing_id = s.env.register_contract(None, Geofencing);
+    let geofencing = GeofencingClient::new(&s.env, &geofencing_id);
+    geofencing.initialize(&s.admin);
+    s.redemption.set_geofencing_contract(&s.admin, &geofencing_id);
+    geofencing
+}
+
+#[test]
+fn test_redeem_fuel_uses_linked_circular_zone() {
+    let s = setup();
+    let geofencing = setup_geofencing(&s);
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    // Zone centred 1km north of the station's local geofence
+    let zone_id = BytesN::from_array(&s.env, &[7u8; 32]);
+    geofencing.create_circular_zone(
+        &s.admin,
+        &zone_id,
+        &String::from_str(&s.env, "Kenol Moi Avenue"),
+        &GeoPoint {
+            lat: -1_277_389,
+            lng: 36_817_222,
+        },
+        &200,
+        &ZoneType::Station,
+    );
+    s.redemption.link_station_zone(
+        &s.admin,
+        &s.station_id,
+        &StationZone::Circular(zone_id.clone()),
+    );
+
+    let in_zone = GpsCoordinates {
+        latitude: -1_277_389,
+        longitude: 36_817_222,
+    };
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &in_zone);
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::OutOfGeofence))
+    );
+
+    // Zone edits in the geofencing contract apply immediately
+    geofencing.deactivate_zone(&s.admin, &zone_id);
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &in_zone),
+        Err(Ok(RedemptionError::OutOfGeofence))
+    );
+}
+
+#[test]
+fn test_redeem_fuel_uses_linked_polygon_zone() {
+    let s = setup();
+    let geofencing = setup_geofencing(&s);
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    let zone_id = BytesN::from_array(&s.env, &[8u8; 32]);
+    let location = station_location();
+    geofencing.create_polygon_zone(
+        &s.admin,
+        &zone_id,
+        &String::from_str(&s.env, "Forecourt"),
+        &vec![
+            &s.env,
+            GeoPoint { lat: location.latitude - 1_000, lng: location.longitude - 1_000 },
+            GeoPoint { lat: location.latitude - 1_000, lng: location.longitude + 1_000 },
+            GeoPoint { lat: location.latitude + 1_000, lng: location.longitude + 1_000 },
+            GeoPoint { lat: location.latitude + 1_000, lng: location.longitude - 1_000 },
+        ],
+        &ZoneType::Station,
+    );
+    s.redemption.link_station_zone(
+        &s.admin,
+        &s.station_id,
+        &StationZone::Polygon(zone_id),
+    );
+
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &location);
+    let outside = GpsCoordinates {
+        latitude: location.latitude + 2_000,
+        longitude: location.longitude,
+    };
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &outside),
+        Err(Ok(RedemptionError::OutOfGeofence))
+    );
+}
+
+#[test]
+fn test_owner_zone_change_returns_station_to_review() {
+    let s = setup();
+    let geofencing = setup_geofencing(&s);
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    let zone_id = BytesN::from_array(&s.env, &[7u8; 32]);
+    geofencing.create_circular_zone(
+        &s.admin,
+        &zone_id,
+        &String::from_str(&s.env, "Kenol Moi Avenue"),
+        &GeoPoint {
+            lat: -1_277_389,
+            lng: 36_817_222,
+        },
+        &200,
+        &ZoneType::Station,
+    );
+    s.redemption.link_station_zone(
+        &s.station_owner,
+        &s.station_id,
+        &StationZone::Circular(zone_id),
+    );
+
+    let station = s.redemption.get_station(&s.station_id);
+    assert_eq!(station.status, StationStatus::Pending);
+    assert_eq!(station.status_reason, String::from_str(&s.env, "Zone changed by owner"));
+    let in_zone = GpsCoordinates {
+        latitude: -1_277_389,
+        longitude: 36_817_222,
+    };
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &in_zone),
+        Err(Ok(RedemptionError::StationNotVerified))
+    );
+
+    s.redemption.approve_station(&s.admin, &s.station_id);
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &in_zone);
+
+    // Zone changes by the admin don't need another review
+    s.redemption
+        .link_station_zone(&s.admin, &s.station_id, &StationZone::Local);
+    assert_eq!(
+        s.redemption.get_station(&s.station_id).status,
+        StationStatus::Verified
+    );
+}
+
+#[test]
+fn test_linked_zone_requires_geofencing_contract() {
+    let s = setup();
+    assert_eq!(
+        s.redemption.try_link_station_zone(
+            &s.station_owner,
+            &s.station_id,
+            &StationZone::Circular(BytesN::from_array(&s.env, &[7u8; 32])),
+        ),
+        Err(Ok(RedemptionError::GeofencingNotConfigured))
+    );
+}
+
+#[test]
+fn test_link_station_zone_requires_existing_zone() {
+    let s = setup();
+    setup_geofencing(&s);
+    let zone_id = BytesN::from_array(&s.env, &[7u8; 32]);
+
+    assert_eq!(
+        s.redemption.try_link_station_zone(
+            &s.station_owner,
+            &s.station_id,
+            &StationZone::Circular(zone_id.clone()),
+        ),
+        Err(Ok(RedemptionError::ZoneNotFound))
+    );
+    assert_eq!(
+        s.redemption.try_link_station_zone(
+            &s.station_owner,
+            &s.station_id,
+            &StationZone::Polygon(zone_id),
+        ),
+        Err(Ok(RedemptionError::ZoneNotFound))
+    );
+    assert_eq!(s.redemption.get_station(&s.station_id).zone, StationZone::Local);
+}
+
+#[test]
+fn test_redeem_fuel_fails_when_linked_zone_missing() {
+    let s = setup();
+    let geofencing = setup_geofencing(&s);
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    let zone_id = BytesN::from_array(&s.env, &[7u8; 32]);
+    geofencing.create_circular_zone(
+        &s.admin,
+        &zone_id,
+        &String::from_str(&s.env, "Kenol Moi Avenue"),
+        &GeoPoint {
+            lat: -1_286_389,
+            lng: 36_817_222,
+        },
+        &200,
+        &ZoneType::Station,
+    );
+    s.redemption.link_station_zone(
+        &s.admin,
+        &s.station_id,
+        &StationZone::Circular(zone_id),
+    );
+
+    // A replacement geofencing contract that doesn't have the zone
+    setup_geofencing(&s);
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::ZoneNotFound))
+    );
+}
+
+#[test]
+fn test_pending_station_cannot_redeem_until_approved() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let station_id = BytesN::from_array(&s.env, &[3u8; 32]);
+    submit_station(&s.env, &s.redemption, &s.station_owner, &station_id, "Total Ngong Road");
+
+    let station = s.redemption.get_station(&station_id);
+    assert_eq!(station.status, StationStatus::Pending);
+    assert_eq!(station.kyc_documents.len(), 1);
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::StationNotVerified))
+    );
+
+    s.redemption.approve_station(&s.admin, &station_id);
+    assert_eq!(
+        s.redemption.get_station(&station_id).status,
+        StationStatus::Verified
+    );
+    s.redemption
+        .redeem_fuel(&s.driver, &station_id, &(100 * FUEL), &station_location());
+}
+
+#[test]
+fn test_rejected_station_records_reason() {
+    let s = setup();
+    let station_id = BytesN::from_array(&s.env, &[3u8; 32]);
+    submit_station(&s.env, &s.redemption, &s.station_owner, &station_id, "Total Ngong Road");
+
+    let reason = String::from_str(&s.env, "Business permit expired");
+    s.redemption.reject_station(&s.admin, &station_id, &reason);
+
+    let station = s.redemption.get_station(&station_id);
+    assert_eq!(station.status, StationStatus::Rejected);
+    assert_eq!(station.status_reason, reason);
+    assert_eq!(
+        s.redemption.try_approve_station(&s.admin, &station_id),
+        Err(Ok(RedemptionError::InvalidStationStatus))
+    );
+}
+
+#[test]
+fn test_suspend_reinstate_and_revoke_station() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let reason = String::from_str(&s.env, "Pump calibration audit");
+
+    s.redemption.suspend_station(&s.admin, &s.station_id, &reason);
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::StationInactive))
+    );
+
+    s.redemption.reinstate_station(&s.admin, &s.station_id);
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location());
+
+    s.redemption.revoke_station(&s.admin, &s.station_id, &reason);
+    assert_eq!(
+        s.redemption.get_station(&s.station_id).status,
+        StationStatus::Revoked
+    );
+    assert_eq!(
+        s.redemption.try_reinstate_station(&s.admin, &s.station_id),
+        Err(Ok(RedemptionError::InvalidStationStatus))
+    );
+}
+
+#[test]
+fn test_only_admin_approves_stations() {
+    let s = setup();
+    let station_id = BytesN::from_array(&s.env, &[3u8; 32]);
+    submit_station(&s.env, &s.redemption, &s.station_owner, &station_id, "Total Ngong Road");
+
+    assert_eq!(
+        s.redemption.try_approve_station(&s.station_owner, &station_id),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_owner_with_multiple_stations() {
+    let s = setup();
+    let second_station = BytesN::from_array(&s.env, &[2u8; 32]);
+    submit_station(&s.env, &s.redemption, &s.station_owner, &second_station, "Kenol Thika Road");
+
+    assert_eq!(
+        s.redemption.get_stations_by_owner(&s.station_owner),
+        vec![&s.env, s.station_id.clone(), second_station]
+    );
+}
+
+#[test]
+fn test_duplicate_station_id_rejected() {
+    let s = setup();
+    let other_owner = Address::generate(&s.env);
+
+    assert_eq!(
+        s.redemption.try_submit_station_application(
+            &other_owner,
+            &s.station_id,
+            &String::from_str(&s.env, "Shell Kenyatta Avenue"),
+            &Geofence {
+                center: station_location(),
+                radius_meters: 200,
+            },
+            &default_prices(&s.env),
+            &vec![&s.env],
+        ),
+        Err(Ok(RedemptionError::StationAlreadyExists))
+    );
+    assert_eq!(s.redemption.get_station(&s.station_id).owner, s.station_owner);
+}
+
+#[test]
+fn test_rejected_application_can_be_resubmitted_by_owner() {
+    let s = setup();
+    let station_id = BytesN::from_array(&s.env, &[3u8; 32]);
+    submit_station(&s.env, &s.redemption, &s.station_owner, &station_id, "Total Ngong Road");
+    s.redemption
+        .reject_station(&s.admin, &station_id, &String::from_str(&s.env, "Missing permit"));
+
+    submit_station(&s.env, &s.redemption, &s.station_owner, &station_id, "Total Ngong Road");
+
+    assert_eq!(
+        s.redemption.get_station(&station_id).status,
+        StationStatus::Pending
+    );
+    assert_eq!(s.redemption.get_stations_by_owner(&s.station_owner).len(), 2);
+}
+
+#[test]
+fn test_transfer_station_moves_payments_to_new_owner() {
+    let s = setup();
+    let new_owner = Address::generate(&s.env);
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    let kyc = vec![&s.env, BytesN::from_array(&s.env, &[9u8; 32])];
+    s.redemption
+        .transfer_station(&s.station_owner, &s.station_id, &new_owner, &kyc);
+
+    assert_eq!(s.redemption.get_stations_by_owner(&s.station_owner).len(), 0);
+    assert_eq!(
+        s.redemption.get_stations_by_owner(&new_owner),
+        vec![&s.env, s.station_id.clone()]
+    );
+
+    // The new owner is vetted before the station can redeem again
+    let station = s.redemption.get_station(&s.station_id);
+    assert_eq!(station.status, StationStatus::Pending);
+    assert_eq!(station.kyc_documents, kyc);
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::StationNotVerified))
+    );
+    s.redemption.approve_station(&s.admin, &s.station_id);
+
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location());
+    assert_eq!(s.token.balance(&new_owner), 100 * FUEL);
+    assert_eq!(s.token.balance(&s.station_owner), 0);
+}
+
+#[test]
+fn test_only_owner_transfers_station() {
+    let s = setup();
+    let stranger = Address::generate(&s.env);
+    let new_owner = Address::generate(&s.env);
+
+    assert_eq!(
+        s.redemption
+            .try_transfer_station(&stranger, &s.station_id, &new_owner, &vec![&s.env]),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_fleet_roster_lists_registered_drivers() {
+    let s = setup();
+    let second_driver = Address::generate(&s.env);
+    let third_driver = Address::generate(&s.env);
+    for (driver, plate) in [(&second_driver, "KDB 456B"), (&third_driver, "KDC 789C")] {
+        s.redemption.register_driver(
+            &s.fleet,
+            driver,
+            &String::from_str(&s.env, plate),
+            &FuelGrade::Diesel,
+            &(60 * FUEL),
+            &default_limits(&s.env),
+        );
+    }
+
+    let first_page = s.redemption.get_fleet_drivers(&s.fleet, &0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get_unchecked(0).address, s.driver);
+    assert_eq!(first_page.get_unchecked(1).address, second_driver);
+
+    let second_page = s.redemption.get_fleet_drivers(&s.fleet, &2, &2);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get_unchecked(0).address, third_driver);
+    assert_eq!(s.redemption.get_fleet_drivers(&s.fleet, &5, &2).len(), 0);
+}
+
+#[test]
+fn test_fleet_summary_counts_and_spend() {
+    let s = setup();
+    let second_driver = Address::generate(&s.env);
+    s.redemption.register_driver(
+        &s.fleet,
+        &second_driver,
+        &String::from_str(&s.env, "KDB 456B"),
+        &FuelGrade::Diesel,
+        &(60 * FUEL),
+        &default_limits(&s.env),
+    );
+    s.redemption.deactivate_driver(&s.fleet, &second_driver);
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+
+    let summary = s.redemption.get_fleet_summary(&s.fleet);
+    assert_eq!(summary.total_drivers, 2);
+    assert_eq!(summary.active_drivers, 1);
+    assert_eq!(summary.deactivated_drivers, 1);
+    assert_eq!(summary.daily_spent, 300 * FUEL);
+    assert_eq!(summary.weekly_spent, 300 * FUEL);
+}
+
+#[test]
+fn test_remove_driver_cleans_up_roster() {
+    let s = setup();
+
+    s.redemption.remove_driver(&s.fleet, &s.driver);
+
+    assert_eq!(s.redemption.get_fleet_drivers(&s.fleet, &0, &10).len(), 0);
+    assert_eq!(
+        s.redemption.try_get_driver(&s.driver),
+        Err(Ok(RedemptionError::DriverNotRegistered))
+    );
+}
+
+#[test]
+fn test_remove_driver_moves_last_driver_into_its_place() {
+    let s = setup();
+    let second_driver = Address::generate(&s.env);
+    let third_driver = Address::generate(&s.env);
+    for (driver, plate) in [(&second_driver, "KDB 456B"), (&third_driver, "KDC 789C")] {
+        s.redemption.register_driver(
+            &s.fleet,
+            driver,
+            &String::from_str(&s.env, plate),
+            &FuelGrade::Diesel,
+            &(60 * FUEL),
+            &default_limits(&s.env),
+        );
+    }
+    s.redemption.deactivate_driver(&s.fleet, &third_driver);
+
+    s.redemption.remove_driver(&s.fleet, &s.driver);
+    let drivers = s.redemption.get_fleet_drivers(&s.fleet, &0, &10);
+    assert_eq!(drivers.len(), 2);
+    assert_eq!(drivers.get_unchecked(0).address, third_driver);
+    assert_eq!(drivers.get_unchecked(1).address, second_driver);
+
+    s.redemption.remove_driver(&s.fleet, &third_driver);
+    let summary = s.redemption.get_fleet_summary(&s.fleet);
+    assert_eq!(summary.total_drivers, 1);
+    assert_eq!(summary.active_drivers, 1);
+    assert_eq!(summary.deactivated_drivers, 0);
+}
+
+#[test]
+fn test_fleet_has_no_driver_cap() {
+    let s = setup();
+    for _ in 0..120 {
+        // Each registration costs the same however large the fleet is
+        s.env.budget().reset_default();
+        s.redemption.register_driver(
+            &s.fleet,
+            &Address::generate(&s.env),
+            &String::from_str(&s.env, "KDB 456B"),
+            &FuelGrade::Diesel,
+            &(60 * FUEL),
+            &default_limits(&s.env),
+        );
+    }
+
+    s.env.budget().reset_default();
+    let summary = s.redemption.get_fleet_summary(&s.fleet);
+    assert_eq!(summary.total_drivers, 121);
+    assert_eq!(summary.active_drivers, 121);
+    assert_eq!(s.redemption.get_fleet_drivers(&s.fleet, &100, &50).len(), 21);
+}
+
+#[test]
+fn test_fleet_spend_totals_follow_windows_and_reversals() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location());
+
+    s.redemption
+        .reverse_redemption(&s.station_owner, &record.id, &String::from_str(&s.env, "Pump fault"));
+    assert_eq!(s.redemption.get_fleet_summary(&s.fleet).daily_spent, 100 * FUEL);
+
+    // Spend stays with the fleet after the driver leaves, until the day rolls over
+    s.redemption.remove_driver(&s.fleet, &s.driver);
+    let summary = s.redemption.get_fleet_summary(&s.fleet);
+    assert_eq!(summary.total_drivers, 0);
+    assert_eq!(summary.daily_spent, 100 * FUEL);
+
+    s.env.ledger().with_mut(|li| li.timestamp += 86_400);
+    assert_eq!(s.redemption.get_fleet_summary(&s.fleet).daily_spent, 0);
+}
+
+#[test]
+fn test_redemption_history_is_stored_and_indexed() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    let mut ids = vec![&s.env];
+    for _ in 0..3 {
+        let record = s
+            .redemption
+            .redeem_fuel(&s.driver, &s.station_id, &(50 * FUEL), &station_location());
+        ids.push_back(record.id);
+    }
+
+    let first = s.redemption.get_redemption(&ids.get_unchecked(0));
+    assert_eq!(first.driver, s.driver);
+    assert_eq!(first.fleet_operator, s.fleet);
+
+    let page = s
+        .redemption
+        .get_driver_redemptions(&s.driver, &0, &2, &None, &None);
+    assert_eq!(page.records.len(), 2);
+    assert_eq!(page.records.get_unchecked(0).id, ids.get_unchecked(0));
+    assert!(page.has_more);
+
+    let page = s
+        .redemption
+        .get_driver_redemptions(&s.driver, &page.next_cursor, &2, &None, &None);
+    assert_eq!(page.records.len(), 1);
+    assert_eq!(page.records.get_unchecked(0).id, ids.get_unchecked(2));
+    assert!(!page.has_more);
+
+    let station_page = s
+        .redemption
+        .get_station_redemptions(&s.station_id, &0, &10, &None, &None);
+    assert_eq!(station_page.records.len(), 3);
+    let fleet_page = s
+        .redemption
+        .get_fleet_redemptions(&s.fleet, &0, &10, &None, &None);
+    assert_eq!(fleet_page.records.len(), 3);
+}
+
+#[test]
+fn test_redemption_history_time_filter() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    for timestamp in [1_000u64, 2_000, 3_000] {
+        s.env.ledger().with_mut(|li| li.timestamp = timestamp);
+        s.redemption
+            .redeem_fuel(&s.driver, &s.station_id, &(50 * FUEL), &station_location());
+    }
+
+    let page = s
+        .redemption
+        .get_driver_redemptions(&s.driver, &0, &10, &Some(1_500), &Some(2_500));
+    assert_eq!(page.records.len(), 1);
+    assert_eq!(page.records.get_unchecked(0).timestamp, 2_000);
+
+    let page = s
+        .redemption
+        .get_station_redemptions(&s.station_id, &0, &10, &Some(2_000), &None);
+    assert_eq!(page.records.len(), 2);
+}
+
+#[test]
+fn test_get_unknown_redemption() {
+    let s = setup();
+    assert_eq!(
+        s.redemption
+            .try_get_redemption(&BytesN::from_array(&s.env, &[7u8; 32])),
+        Err(Ok(RedemptionError::RedemptionNotFound))
+    );
+}
+
+// 2024-03-31 23:00:00 UTC, a Sunday
+const SUNDAY_LATE_UTC: u64 = 1_711_926_000;
+const HOUR: u64 = 3_600;
+
+#[test]
+fn test_daily_window_resets_at_midnight_not_after_24h() {
+    let s = setup();
+    s.token.mint(&s.driver, &(10_000 * FUEL));
+    s.env.ledger().with_mut(|li| li.timestamp = SUNDAY_LATE_UTC);
+
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(500 * FUEL), &station_location());
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(500 * FUEL), &station_location());
+    assert_eq!(s.redemption.get_remaining_daily_limit(&s.driver), 0);
+
+    // Two hours later is a new calendar day, week and month in UTC
+    s.env.ledger().with_mut(|li| li.timestamp = SUNDAY_LATE_UTC + 2 * HOUR);
+    let remaining = s.redemption.get_remaining_limits(&s.driver);
+    assert_eq!(remaining.daily, 1_000 * FUEL);
+    assert_eq!(remaining.weekly, 5_000 * FUEL);
+    assert_eq!(remaining.monthly, 20_000 * FUEL);
+}
+
+#[test]
+fn test_windows_follow_fleet_utc_offset() {
+    let s = setup();
+    s.token.mint(&s.driver, &(10_000 * FUEL));
+    // Nairobi, UTC+3: 23:00 UTC Sunday is already 02:00 Monday locally
+    s.redemption.set_fleet_utc_offset(&s.fleet, &(3 * 3_600));
+    s.env.ledger().with_mut(|li| li.timestamp = SUNDAY_LATE_UTC);
+
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(500 * FUEL), &station_location());
+
+    // 20:00 local on Monday is still the same local day
+    s.env.ledger().with_mut(|li| li.timestamp = SUNDAY_LATE_UTC + 18 * HOUR);
+    let remaining = s.redemption.get_remaining_limits(&s.driver);
+    assert_eq!(remaining.daily, 500 * FUEL);
+    assert_eq!(remaining.weekly, 4_500 * FUEL);
+    assert_eq!(remaining.monthly, 19_500 * FUEL);
+
+    // 01:00 local on Tuesday starts a new day but not a new week or month
+    s.env.ledger().with_mut(|li| li.timestamp = SUNDAY_LATE_UTC + 23 * HOUR);
+    let remaining = s.redemption.get_remaining_limits(&s.driver);
+    assert_eq!(remaining.daily, 1_000 * FUEL);
+    assert_eq!(remaining.weekly, 4_500 * FUEL);
+}
+
+#[test]
+fn test_monthly_limit_enforced() {
+    let s = setup();
+    s.token.mint(&s.driver, &(10_000 * FUEL));
+    let mut limits = default_limits(&s.env);
+    limits.monthly_limit = 400 * FUEL;
+    s.redemption
+        .update_spending_limits(&s.fleet, &s.driver, &limits);
+
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(200 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::MonthlyLimitExceeded))
+    );
+}
+
+#[test]
+fn test_invalid_utc_offset_rejected() {
+    let s = setup();
+    assert_eq!(
+        s.redemption.try_set_fleet_utc_offset(&s.fleet, &(15 * 3_600)),
+        Err(Ok(RedemptionError::InvalidUtcOffset))
+    );
+}
+
+#[test]
+fn test_liters_computed_from_vehicle_grade_price() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    let diesel = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    assert_eq!(diesel.fuel_grade, FuelGrade::Diesel);
+    assert_eq!(diesel.liters, 2 * FUEL);
+
+    s.redemption
+        .set_vehicle_fuel_grade(&s.fleet, &s.driver, &FuelGrade::Petrol);
+    let petrol = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(360 * FUEL), &station_location());
+    assert_eq!(petrol.fuel_grade, FuelGrade::Petrol);
+    assert_eq!(petrol.liters, 2 * FUEL);
+}
+
+#[test]
+fn test_station_without_vehicle_grade_rejects_redemption() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption
+        .set_vehicle_fuel_grade(&s.fleet, &s.driver, &FuelGrade::Kerosene);
+
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::FuelGradeNotSold))
+    );
+
+    s.redemption.update_fuel_price(
+        &s.station_owner,
+        &s.station_id,
+        &FuelGrade::Kerosene,
+        &(125 * FUEL),
+        &0,
+    );
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(250 * FUEL), &station_location());
+    assert_eq!(record.liters, 2 * FUEL);
+
+    s.redemption
+        .remove_fuel_grade(&s.station_owner, &s.station_id, &FuelGrade::Kerosene);
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::FuelGradeNotSold))
+    );
+}
+
+fn diesel_bounds() -> PriceBounds {
+    PriceBounds {
+        min_price: 100 * FUEL,
+        max_price: 250 * FUEL,
+        max_change_bps: 1_000,
+    }
+}
+
+#[test]
+fn test_price_update_rejects_invalid_prices() {
+    let s = setup();
+
+    assert_eq!(
+        s.redemption
+            .try_update_fuel_price(&s.station_owner, &s.station_id, &FuelGrade::Diesel, &0, &0),
+        Err(Ok(RedemptionError::InvalidPrice))
+    );
+
+    s.redemption
+        .set_price_bounds(&s.admin, &FuelGrade::Diesel, &diesel_bounds());
+    assert_eq!(
+        s.redemption.try_update_fuel_price(
+            &s.station_owner,
+            &s.station_id,
+            &FuelGrade::Diesel,
+            &(1_500 * FUEL),
+            &0,
+        ),
+        Err(Ok(RedemptionError::InvalidPrice))
+    );
+    // 150 -> 170 is a 13.3% change against a 10% limit
+    assert_eq!(
+        s.redemption.try_update_fuel_price(
+            &s.station_owner,
+            &s.station_id,
+            &FuelGrade::Diesel,
+            &(170 * FUEL),
+            &0,
+        ),
+        Err(Ok(RedemptionError::PriceChangeTooLarge))
+    );
+
+    s.redemption
+        .update_fuel_price(&s.station_owner, &s.station_id, &FuelGrade::Diesel, &(165 * FUEL), &0);
+    assert_eq!(
+        s.redemption
+            .get_station(&s.station_id)
+            .fuel_prices
+            .get_unchecked(FuelGrade::Diesel),
+        165 * FUEL
+    );
+}
+
+#[test]
+fn test_application_prices_must_respect_bounds() {
+    let s = setup();
+    s.redemption
+        .set_price_bounds(&s.admin, &FuelGrade::Diesel, &diesel_bounds());
+
+    assert_eq!(
+        s.redemption.try_submit_station_application(
+            &s.station_owner,
+            &BytesN::from_array(&s.env, &[3u8; 32]),
+            &String::from_str(&s.env, "Total Ngong Road"),
+            &Geofence {
+                center: station_location(),
+                radius_meters: 200,
+            },
+            &map![&s.env, (FuelGrade::Diesel, 50 * FUEL)],
+            &vec![&s.env],
+        ),
+        Err(Ok(RedemptionError::InvalidPrice))
+    );
+}
+
+#[test]
+fn test_redemption_uses_price_effective_at_redemption_time() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    s.redemption
+        .update_fuel_price(&s.station_owner, &s.station_id, &FuelGrade::Diesel, &(200 * FUEL), &5_000);
+    assert_eq!(
+        s.redemption
+            .try_update_fuel_price(&s.station_owner, &s.station_id, &FuelGrade::Diesel, &(180 * FUEL), &4_000),
+        Err(Ok(RedemptionError::InvalidEffectiveTime))
+    );
+
+    let before = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    assert_eq!(before.price_per_liter, 150 * FUEL);
+    assert_eq!(before.liters, 2 * FUEL);
+
+    s.env.ledger().with_mut(|li| li.timestamp = 5_000);
+    let after = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    assert_eq!(after.price_per_liter, 200 * FUEL);
+    assert_eq!(after.liters, 15 * FUEL / 10);
+
+    let history = s
+        .redemption
+        .get_price_history(&s.station_id, &FuelGrade::Diesel);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get_unchecked(1).effective_from, 5_000);
+}
+
+#[test]
+fn test_readded_grade_waits_for_its_new_price() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.env.ledger().with_mut(|li| li.timestamp = 1_000);
+    s.redemption
+        .set_price_bounds(&s.admin, &FuelGrade::Diesel, &diesel_bounds());
+
+    s.redemption
+        .remove_fuel_grade(&s.station_owner, &s.station_id, &FuelGrade::Diesel);
+    // Re-added prices are still bounded by the last price sold at
+    assert_eq!(
+        s.redemption.try_update_fuel_price(
+            &s.station_owner,
+            &s.station_id,
+            &FuelGrade::Diesel,
+            &(200 * FUEL),
+            &5_000,
+        ),
+        Err(Ok(RedemptionError::PriceChangeTooLarge))
+    );
+    s.redemption
+        .update_fuel_price(&s.station_owner, &s.station_id, &FuelGrade::Diesel, &(160 * FUEL), &5_000);
+
+    // The price from before the removal no longer applies
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::FuelGradeNotSold))
+    );
+    s.env.ledger().with_mut(|li| li.timestamp = 5_000);
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(160 * FUEL), &station_location());
+    assert_eq!(record.price_per_liter, 160 * FUEL);
+}
+
+#[test]
+fn test_resubmitted_application_withdraws_dropped_grades() {
+    let s = setup();
+    let station_id = BytesN::from_array(&s.env, &[3u8; 32]);
+    submit_station(&s.env, &s.redemption, &s.station_owner, &station_id, "Total Ngong Road");
+    s.redemption
+        .reject_station(&s.admin, &station_id, &String::from_str(&s.env, "Missing permit"));
+
+    s.env.ledger().with_mut(|li| li.timestamp = 1_000);
+    s.redemption.submit_station_application(
+        &s.station_owner,
+        &station_id,
+        &String::from_str(&s.env, "Total Ngong Road"),
+        &Geofence {
+            center: station_location(),
+            radius_meters: 200,
+        },
+        &map![&s.env, (FuelGrade::Petrol, 180 * FUEL)],
+        &vec![&s.env, BytesN::from_array(&s.env, &[9u8; 32])],
+    );
+    s.redemption.approve_station(&s.admin, &station_id);
+    s.redemption
+        .update_fuel_price(&s.station_owner, &station_id, &FuelGrade::Diesel, &(150 * FUEL), &5_000);
+
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &station_id, &(100 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::FuelGradeNotSold))
+    );
+}
+
+#[test]
+fn test_disputed_redemption_reversed_by_fleet_operator() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+
+    s.redemption.open_dispute(
+        &s.driver,
+        &record.id,
+        &String::from_str(&s.env, "Not my vehicle"),
+    );
+    assert_eq!(
+        s.redemption.get_redemption(&record.id).status,
+        RedemptionStatus::Disputed
+    );
+    s.redemption.respond_to_dispute(
+        &s.station_owner,
+        &record.id,
+        &String::from_str(&s.env, "Pump 3 CCTV shows a different plate"),
+    );
+
+    // Refunds are pulled from the station owner through an allowance
+    assert_eq!(
+        s.redemption.try_resolve_dispute(&s.fleet, &record.id, &true),
+        Err(Ok(RedemptionError::RefundNotApproved))
+    );
+    let expiration_ledger = s.env.ledger().sequence() + 1000;
+    s.token.approve(
+        &s.station_owner,
+        &s.redemption.address,
+        &(300 * FUEL),
+        &expiration_ledger,
+    );
+    s.redemption.resolve_dispute(&s.fleet, &record.id, &true);
+
+    assert_eq!(s.token.balance(&s.driver), 1_000 * FUEL);
+    assert_eq!(s.token.balance(&s.station_owner), 0);
+    assert_eq!(
+        s.redemption.get_redemption(&record.id).status,
+        RedemptionStatus::Reversed
+    );
+    assert_eq!(
+        s.redemption.get_dispute(&record.id).status,
+        DisputeStatus::Reversed
+    );
+    let driver = s.redemption.get_driver(&s.driver);
+    assert_eq!(driver.daily_spent, 0);
+    assert_eq!(driver.weekly_spent, 0);
+    assert_eq!(driver.total_redemptions, 0);
+    assert_eq!(s.redemption.get_station(&s.station_id).total_redemptions, 0);
+}
+
+#[test]
+fn test_dispute_upheld_by_admin() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    s.redemption
+        .open_dispute(&s.driver, &record.id, &String::from_str(&s.env, "Short fill"));
+
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.redemption.try_resolve_dispute(&stranger, &record.id, &false),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+    s.redemption.resolve_dispute(&s.admin, &record.id, &false);
+
+    assert_eq!(
+        s.redemption.get_dispute(&record.id).status,
+        DisputeStatus::Upheld
+    );
+    assert_eq!(
+        s.redemption.get_redemption(&record.id).status,
+        RedemptionStatus::Completed
+    );
+    assert_eq!(s.token.balance(&s.station_owner), 300 * FUEL);
+    assert_eq!(
+        s.redemption.try_resolve_dispute(&s.admin, &record.id, &true),
+        Err(Ok(RedemptionError::InvalidDisputeStatus))
+    );
+
+    // The upheld resolution stands; the driver cannot dispute again
+    assert_eq!(
+        s.redemption
+            .try_open_dispute(&s.driver, &record.id, &String::from_str(&s.env, "Short fill")),
+        Err(Ok(RedemptionError::DisputeAlreadyExists))
+    );
+    let dispute = s.redemption.get_dispute(&record.id);
+    assert_eq!(dispute.status, DisputeStatus::Upheld);
+    assert_eq!(dispute.reason, String::from_str(&s.env, "Short fill"));
+}
+
+#[test]
+fn test_station_reverses_erroneous_redemption() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+
+    s.redemption.reverse_redemption(
+        &s.station_owner,
+        &record.id,
+        &String::from_str(&s.env, "Pump fault, no fuel dispensed"),
+    );
+
+    assert_eq!(s.token.balance(&s.driver), 1_000 * FUEL);
+    assert_eq!(s.redemption.get_remaining_daily_limit(&s.driver), 1_000 * FUEL);
+    assert_eq!(
+        s.redemption.try_open_dispute(
+            &s.driver,
+            &record.id,
+            &String::from_str(&s.env, "Too late")
+        ),
+        Err(Ok(RedemptionError::RedemptionAlreadyReversed))
+    );
+}
+
+#[test]
+fn test_dispute_window_and_ownership() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    let reason = String::from_str(&s.env, "Not my vehicle");
+
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.redemption.try_open_dispute(&stranger, &record.id, &reason),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+
+    s.env
+        .ledger()
+        .with_mut(|li| li.timestamp = record.timestamp + 31 * 86_400);
+    assert_eq!(
+        s.redemption.try_open_dispute(&s.driver, &record.id, &reason),
+        Err(Ok(RedemptionError::DisputeWindowClosed))
+    );
+}
+
+#[test]
+fn test_daily_stats_and_settlement_statement() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let day = 19_813u64;
+    s.env.ledger().with_mut(|li| li.timestamp = day * 86_400 + 3_600);
+
+    let first = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(150 * FUEL), &station_location());
+    s.redemption.reverse_redemption(
+        &s.station_owner,
+        &first.id,
+        &String::from_str(&s.env, "Pump fault"),
+    );
+
+    let stats = s.redemption.get_daily_stats(&s.station_id, &day);
+    assert_eq!(stats.redemption_count, 2);
+    assert_eq!(stats.total_amount, 450 * FUEL);
+    assert_eq!(stats.total_liters, 3 * FUEL);
+    assert_eq!(stats.reversed_count, 1);
+    assert_eq!(stats.reversed_amount, 300 * FUEL);
+    assert!(!stats.closed);
+
+    assert_eq!(
+        s.redemption
+            .try_close_settlement_period(&s.station_owner, &s.station_id, &day),
+        Err(Ok(RedemptionError::SettlementPeriodOpen))
+    );
+
+    s.env.ledger().with_mut(|li| li.timestamp = (day + 1) * 86_400);
+    let statement = s
+        .redemption
+        .close_settlement_period(&s.station_owner, &s.station_id, &day);
+    assert!(statement.closed);
+    assert_eq!(statement.redemptions_hash, stats.redemptions_hash);
+    assert_ne!(
+        statement.redemptions_hash,
+        BytesN::from_array(&s.env, &[0u8; 32])
+    );
+    assert_eq!(
+        s.redemption
+            .try_close_settlement_period(&s.admin, &s.station_id, &day),
+        Err(Ok(RedemptionError::SettlementAlreadyClosed))
+    );
+
+    let statements = s
+        .redemption
+        .get_settlement_statements(&s.station_id, &(day - 1), &(day + 1));
+    assert_eq!(statements.len(), 3);
+    assert_eq!(statements.get_unchecked(1), statement);
+    assert_eq!(statements.get_unchecked(2).redemption_count, 0);
+}
+
+#[test]
+fn test_only_owner_or_admin_closes_settlement() {
+    let s = setup();
+    let stranger = Address::generate(&s.env);
+    s.env.ledger().with_mut(|li| li.timestamp = 2 * 86_400);
+
+    assert_eq!(
+        s.redemption
+            .try_close_settlement_period(&stranger, &s.station_id, &0),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+    s.redemption
+        .close_settlement_period(&s.admin, &s.station_id, &0);
+}
+
+#[test]
+fn test_min_interval_rule_rejects_rapid_redemptions() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption.set_fraud_rule(
+        &s.admin,
+        &FraudRule::MinInterval,
+        &RuleConfig {
+            action: RuleAction::Reject,
+            threshold: 600,
+        },
+    );
+    s.env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(30 * FUEL), &station_location());
+    s.env.ledger().with_mut(|li| li.timestamp = 10_060);
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(30 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::RedemptionTooFrequent))
+    );
+
+    s.env.ledger().with_mut(|li| li.timestamp = 10_600);
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(30 * FUEL), &station_location());
+}
+
+#[test]
+fn test_min_interval_rule_saturates_huge_threshold() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption.set_fraud_rule(
+        &s.admin,
+        &FraudRule::MinInterval,
+        &RuleConfig {
+            action: RuleAction::Reject,
+            threshold: u64::MAX,
+        },
+    );
+    s.env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(30 * FUEL), &station_location());
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(30 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::RedemptionTooFrequent))
+    );
+}
+
+#[test]
+fn test_register_driver_rejects_negative_tank_capacity() {
+    let s = setup();
+    let driver = Address::generate(&s.env);
+    assert_eq!(
+        s.redemption.try_register_driver(
+            &s.fleet,
+            &driver,
+            &String::from_str(&s.env, "KDB 456B"),
+            &FuelGrade::Diesel,
+            &-1,
+            &default_limits(&s.env),
+        ),
+        Err(Ok(RedemptionError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_tank_capacity_rule_rejects_oversized_fill() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    // A motorbike with a 2 liter tank, allowed up to 110%: 2.2 liters
+    s.redemption
+        .set_vehicle_tank_capacity(&s.fleet, &s.driver, &(2 * FUEL));
+    s.redemption.set_fraud_rule(
+        &s.admin,
+        &FraudRule::TankCapacity,
+        &RuleConfig {
+            action: RuleAction::Reject,
+            threshold: 11_000,
+        },
+    );
+
+    // 3 liters of diesel at 150
+    assert_eq!(
+        s.redemption
+            .try_redeem_fuel(&s.driver, &s.station_id, &(450 * FUEL), &station_location()),
+        Err(Ok(RedemptionError::ExceedsTankCapacity))
+    );
+    // 2 liters
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+
+    // An unknown capacity skips the rule
+    s.redemption
+        .set_vehicle_tank_capacity(&s.fleet, &s.driver, &0);
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(450 * FUEL), &station_location());
+}
+
+#[test]
+fn test_flagged_rule_emits_fraud_flag_and_allows_redemption() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption.set_fraud_rule(
+        &s.admin,
+        &FraudRule::MinInterval,
+        &RuleConfig {
+            action: RuleAction::Flag,
+            threshold: 600,
+        },
+    );
+
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(30 * FUEL), &station_location());
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(30 * FUEL), &station_location());
+
+    let events = s.env.events().all();
+    let flag = events.iter().find(|(_, topics, _)| {
+        topics.len() == 3
+            && Symbol::try_from_val(&s.env, &topics.get_unchecked(0))
+                == Ok(Symbol::new(&s.env, "fraud_flag"))
+    });
+    let (_, topics, data) = flag.expect("fraud_flag event");
+    assert_eq!(
+        u32::try_from_val(&s.env, &topics.get_unchecked(2)),
+        Ok(FraudRule::MinInterval as u32)
+    );
+    assert_eq!(BytesN::<32>::try_from_val(&s.env, &data), Ok(record.id));
+}
+
+fn voucher_secret(env: &Env) -> (Bytes, BytesN<32>) {
+    let secret = Bytes::from_slice(env, b"qr-secret-4f1c9a");
+    let code_hash = env.crypto().sha256(&secret).into();
+    (secret, code_hash)
+}
+
+fn commit_voucher(s: &Setup, owner: &Address, station_id: &BytesN<32>, secret: &Bytes) {
+    let mut preimage = secret.clone();
+    preimage.append(&station_id.clone().into());
+    let commitment: BytesN<32> = s.env.crypto().sha256(&preimage).into();
+    let code_hash: BytesN<32> = s.env.crypto().sha256(secret).into();
+    s.redemption
+        .commit_voucher(owner, station_id, &code_hash, &commitment);
+}
+
+#[test]
+fn test_station_redeems_voucher_with_secret() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let expiration_ledger = s.env.ledger().sequence() + 1000;
+    s.token
+        .approve(&s.driver, &s.redemption.address, &(300 * FUEL), &expiration_ledger);
+    let (secret, code_hash) = voucher_secret(&s.env);
+    s.redemption.issue_voucher(
+        &s.driver,
+        &code_hash,
+        &(300 * FUEL),
+        &(s.env.ledger().timestamp() + 3_600),
+        &VoucherScope::Station(s.station_id.clone()),
+    );
+
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &s.station_owner,
+            &s.station_id,
+            &secret,
+            &(300 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::VoucherNotCommitted))
+    );
+    commit_voucher(&s, &s.station_owner, &s.station_id, &secret);
+    let record = s.redemption.redeem_voucher(
+        &s.station_owner,
+        &s.station_id,
+        &secret,
+        &(300 * FUEL),
+        &station_location(),
+    );
+
+    assert_eq!(record.driver, s.driver);
+    assert_eq!(s.token.balance(&s.station_owner), 300 * FUEL);
+    let voucher = s.redemption.get_voucher(&code_hash);
+    assert_eq!(voucher.status, VoucherStatus::Redeemed);
+    assert_eq!(voucher.redemption_id, record.id);
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &s.station_owner,
+            &s.station_id,
+            &secret,
+            &(100 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::VoucherAlreadyUsed))
+    );
+}
+
+#[test]
+fn test_voucher_checks_secret_amount_station_and_expiry() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let expiration_ledger = s.env.ledger().sequence() + 1000;
+    s.token
+        .approve(&s.driver, &s.redemption.address, &(1_000 * FUEL), &expiration_ledger);
+    let (secret, code_hash) = voucher_secret(&s.env);
+    let expires_at = s.env.ledger().timestamp() + 3_600;
+    s.redemption.issue_voucher(
+        &s.driver,
+        &code_hash,
+        &(300 * FUEL),
+        &expires_at,
+        &VoucherScope::Station(s.station_id.clone()),
+    );
+
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &s.station_owner,
+            &s.station_id,
+            &Bytes::from_slice(&s.env, b"guessed"),
+            &(100 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::VoucherNotFound))
+    );
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &s.station_owner,
+            &s.station_id,
+            &secret,
+            &(400 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::VoucherAmountExceeded))
+    );
+
+    let other_station = BytesN::from_array(&s.env, &[2u8; 32]);
+    submit_station(&s.env, &s.redemption, &s.station_owner, &other_station, "Kenol Thika Road");
+    s.redemption.approve_station(&s.admin, &other_station);
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &s.station_owner,
+            &other_station,
+            &secret,
+            &(100 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::StationNotAllowed))
+    );
+
+    s.env.ledger().with_mut(|li| li.timestamp = expires_at);
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &s.station_owner,
+            &s.station_id,
+            &secret,
+            &(100 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::VoucherExpired))
+    );
+}
+
+#[test]
+fn test_voucher_requires_driver_allowance_and_can_be_cancelled() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let (secret, code_hash) = voucher_secret(&s.env);
+    s.redemption.issue_voucher(
+        &s.driver,
+        &code_hash,
+        &(300 * FUEL),
+        &(s.env.ledger().timestamp() + 3_600),
+        &VoucherScope::AnyStation,
+    );
+
+    commit_voucher(&s, &s.station_owner, &s.station_id, &secret);
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &s.station_owner,
+            &s.station_id,
+            &secret,
+            &(100 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::PaymentNotApproved))
+    );
+    assert_eq!(
+        s.redemption.try_issue_voucher(
+            &s.driver,
+            &code_hash,
+            &(300 * FUEL),
+            &(s.env.ledger().timestamp() + 3_600),
+            &VoucherScope::AnyStation,
+        ),
+        Err(Ok(RedemptionError::VoucherAlreadyExists))
+    );
+
+    s.redemption.cancel_voucher(&s.driver, &code_hash);
+    assert_eq!(
+        s.redemption.get_voucher(&code_hash).status,
+        VoucherStatus::Cancelled
+    );
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &s.station_owner,
+            &s.station_id,
+            &secret,
+            &(100 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::VoucherAlreadyUsed))
+    );
+}
+
+#[test]
+fn test_revealed_voucher_secret_cannot_be_redeemed_elsewhere() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let (secret, code_hash) = voucher_secret(&s.env);
+    s.redemption.issue_voucher(
+        &s.driver,
+        &code_hash,
+        &(300 * FUEL),
+        &(s.env.ledger().timestamp() + 3_600),
+        &VoucherScope::AnyStation,
+    );
+
+    // The station's redemption fails without an allowance, revealing the secret
+    commit_voucher(&s, &s.station_owner, &s.station_id, &secret);
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &s.station_owner,
+            &s.station_id,
+            &secret,
+            &(100 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::PaymentNotApproved))
+    );
+
+    // Another station commits to the now public secret too late
+    let other_owner = Address::generate(&s.env);
+    let other_station = BytesN::from_array(&s.env, &[2u8; 32]);
+    submit_station(&s.env, &s.redemption, &other_owner, &other_station, "Kenol Thika Road");
+    s.redemption.approve_station(&s.admin, &other_station);
+    commit_voucher(&s, &other_owner, &other_station, &secret);
+    let expiration_ledger = s.env.ledger().sequence() + 1000;
+    s.token
+        .approve(&s.driver, &s.redemption.address, &(300 * FUEL), &expiration_ledger);
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &other_owner,
+            &other_station,
+            &secret,
+            &(100 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::VoucherNotCommitted))
+    );
+
+    s.redemption.redeem_voucher(
+        &s.station_owner,
+        &s.station_id,
+        &secret,
+        &(100 * FUEL),
+        &station_location(),
+    );
+    assert_eq!(s.token.balance(&s.station_owner), 100 * FUEL);
+}
+
+#[test]
+fn test_voucher_commitments_are_one_per_station() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let expiration_ledger = s.env.ledger().sequence() + 1000;
+    s.token
+        .approve(&s.driver, &s.redemption.address, &(300 * FUEL), &expiration_ledger);
+    let (secret, code_hash) = voucher_secret(&s.env);
+    s.redemption.issue_voucher(
+        &s.driver,
+        &code_hash,
+        &(300 * FUEL),
+        &(s.env.ledger().timestamp() + 3_600),
+        &VoucherScope::AnyStation,
+    );
+
+    // Another station floods the voucher with junk commitments
+    let other_owner = Address::generate(&s.env);
+    let other_station = BytesN::from_array(&s.env, &[2u8; 32]);
+    submit_station(&s.env, &s.redemption, &other_owner, &other_station, "Kenol Thika Road");
+    s.redemption.approve_station(&s.admin, &other_station);
+    for i in 0..10u8 {
+        s.redemption.commit_voucher(
+            &other_owner,
+            &other_station,
+            &code_hash,
+            &BytesN::from_array(&s.env, &[i; 32]),
+        );
+    }
+
+    // The driver's station can still commit and redeem
+    commit_voucher(&s, &s.station_owner, &s.station_id, &secret);
+    // A replaced commitment moves behind the ones made before it
+    commit_voucher(&s, &other_owner, &other_station, &secret);
+    assert_eq!(
+        s.redemption.try_redeem_voucher(
+            &other_owner,
+            &other_station,
+            &secret,
+            &(100 * FUEL),
+            &station_location(),
+        ),
+        Err(Ok(RedemptionError::VoucherNotCommitted))
+    );
+    s.redemption.redeem_voucher(
+        &s.station_owner,
+        &s.station_id,
+        &secret,
+        &(100 * FUEL),
+        &station_location(),
+    );
+}
+
+#[test]
+fn test_redemption_ids_are_unique_per_deployment() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+
+    // A second deployment sharing the token, station and driver
+    let other_id = s.env.register_contract(None, VoucherRedemption);
+    let other = VoucherRedemptionClient::new(&s.env, &other_id);
+    other.initialize(&s.admin, &s.token.address);
+    submit_station(&s.env, &other, &s.station_owner, &s.station_id, "Kenol Moi Avenue");
+    other.approve_station(&s.admin, &s.station_id);
+    other.register_driver(
+        &s.fleet,
+        &s.driver,
+        &String::from_str(&s.env, "KDA 123A"),
+        &FuelGrade::Diesel,
+        &(60 * FUEL),
+        &default_limits(&s.env),
+    );
+
+    let first = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location());
+    let second = other.redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location());
+
+    assert_eq!(first.sequence, second.sequence);
+    assert_ne!(first.id, second.id);
+}
+
+#[test]
+fn test_verify_receipt() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+
+    assert!(s.redemption.verify_receipt(&record));
+
+    let mut inflated = record.clone();
+    inflated.amount = 3_000 * FUEL;
+    assert!(!s.redemption.verify_receipt(&inflated));
+
+    let mut forged = record.clone();
+    forged.sequence = 2;
+    assert!(!s.redemption.verify_receipt(&forged));
+
+    // A receipt stays valid after the redemption is disputed
+    s.redemption
+        .open_dispute(&s.driver, &record.id, &String::from_str(&s.env, "Short fill"));
+    assert!(s.redemption.verify_receipt(&record));
+}
+
+fn authorized_last_call(env: &Env, address: &Address) -> bool {
+    env.auths().iter().any(|(authorizer, _)| authorizer == address)
+}
+
+#[test]
+fn test_register_driver_cannot_hijack_existing_driver() {
+    let s = setup();
+    let other_fleet = Address::generate(&s.env);
+
+    assert_eq!(
+        s.redemption.try_register_driver(
+            &other_fleet,
+            &s.driver,
+            &String::from_str(&s.env, "KDZ 999Z"),
+            &FuelGrade::Petrol,
+            &(60 * FUEL),
+            &default_limits(&s.env),
+        ),
+        Err(Ok(RedemptionError::DriverAlreadyRegistered))
+    );
+    assert_eq!(s.redemption.get_driver(&s.driver).fleet_operator, s.fleet);
+}
+
+#[test]
+fn test_transfer_driver_between_fleets() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    let new_fleet = Address::generate(&s.env);
+    let mut new_limits = default_limits(&s.env);
+    new_limits.daily_limit = 200 * FUEL;
+
+    s.redemption
+        .transfer_driver(&s.fleet, &new_fleet, &s.driver, &new_limits);
+
+    assert!(authorized_last_call(&s.env, &s.fleet));
+    assert!(authorized_last_call(&s.env, &new_fleet));
+    assert!(!authorized_last_call(&s.env, &s.driver));
+
+    let driver = s.redemption.get_driver(&s.driver);
+    assert_eq!(driver.fleet_operator, new_fleet);
+    assert_eq!(driver.spending_limits, new_limits);
+    assert_eq!(driver.daily_spent, 0);
+    assert_eq!(driver.total_redemptions, 1);
+    assert_eq!(
+        s.redemption
+            .get_driver_redemptions(&s.driver, &0, &10, &None, &None)
+            .records
+            .len(),
+        1
+    );
+    assert_eq!(s.redemption.get_fleet_drivers(&s.fleet, &0, &10).len(), 0);
+    assert_eq!(s.redemption.get_fleet_drivers(&new_fleet, &0, &10).len(), 1);
+
+    // The old fleet no longer manages the driver
+    assert_eq!(
+        s.redemption.try_deactivate_driver(&s.fleet, &s.driver),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_reversal_after_transfer_keeps_new_fleet_spend() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    let record = s
+        .redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(300 * FUEL), &station_location());
+    let new_fleet = Address::generate(&s.env);
+    s.redemption
+        .transfer_driver(&s.fleet, &new_fleet, &s.driver, &default_limits(&s.env));
+    s.redemption
+        .redeem_fuel(&s.driver, &s.station_id, &(100 * FUEL), &station_location());
+
+    s.redemption
+        .reverse_redemption(&s.station_owner, &record.id, &String::from_str(&s.env, "Pump fault"));
+
+    let driver = s.redemption.get_driver(&s.driver);
+    assert_eq!(driver.daily_spent, 100 * FUEL);
+    assert_eq!(driver.weekly_spent, 100 * FUEL);
+    assert_eq!(driver.monthly_spent, 100 * FUEL);
+    assert_eq!(driver.total_redemptions, 1);
+    assert_eq!(s.redemption.get_fleet_summary(&s.fleet).daily_spent, 0);
+    assert_eq!(s.redemption.get_fleet_summary(&new_fleet).daily_spent, 100 * FUEL);
+}
+
+#[test]
+fn test_transfer_driver_with_driver_consent() {
+    let s = setup();
+    s.redemption.set_transfer_driver_consent(&s.admin, &true);
+    let new_fleet = Address::generate(&s.env);
+
+    s.redemption
+        .transfer_driver(&s.fleet, &new_fleet, &s.driver, &default_limits(&s.env));
+
+    assert!(authorized_last_call(&s.env, &s.driver));
+}
+
+#[test]
+fn test_only_current_fleet_transfers_driver() {
+    let s = setup();
+    let other_fleet = Address::generate(&s.env);
+    let new_fleet = Address::generate(&s.env);
+
+    assert_eq!(
+        s.redemption.try_transfer_driver(
+            &other_fleet,
+            &new_fleet,
+            &s.driver,
+            &default_limits(&s.env)
+        ),
+        Err(Ok(RedemptionError::Unauthorized))
+    );
+}
+
+const WEEKDAYS: u32 = 0b001_1111;
+// 2024-04-01 00:00:00 UTC, a Monday
+const MONDAY_UTC: u64 = 1_711_929_600;
+
+fn redeem_at(s: &Setup, timestamp: u64) -> Result<(), RedemptionError> {
+    s.env.ledger().with_mut(|li| li.timestamp = timestamp);
+    match s
+        .redemption
+        .try_redeem_fuel(&s.driver, &s.station_id, &(10 * FUEL), &station_location())
+    {
+        Ok(_) => Ok(()),
+        Err(Ok(error)) => Err(error),
+        Err(Err(_)) => panic!("unexpected host error"),
+    }
+}
+
+#[test]
+fn test_fleet_time_windows_block_night_and_sunday() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    // Every day but Sunday, 05:00 to 22:00
+    s.redemption.set_fleet_time_windows(
+        &s.fleet,
+        &vec![
+            &s.env,
+            TimeWindow {
+                days: 0b011_1111,
+                start_minute: 5 * 60,
+                end_minute: 22 * 60,
+            },
+        ],
+    );
+
+    assert_eq!(redeem_at(&s, MONDAY_UTC + 8 * HOUR), Ok(()));
+    assert_eq!(
+        redeem_at(&s, MONDAY_UTC + 23 * HOUR),
+        Err(RedemptionError::OutsideRedemptionWindow)
+    );
+    assert_eq!(
+        redeem_at(&s, MONDAY_UTC + 24 * HOUR + 4 * HOUR),
+        Err(RedemptionError::OutsideRedemptionWindow)
+    );
+    // Sunday midday
+    assert_eq!(
+        redeem_at(&s, MONDAY_UTC + 6 * 24 * HOUR + 12 * HOUR),
+        Err(RedemptionError::OutsideRedemptionWindow)
+    );
+}
+
+#[test]
+fn test_time_windows_use_fleet_utc_offset() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption.set_fleet_utc_offset(&s.fleet, &(3 * 3_600));
+    s.redemption.set_fleet_time_windows(
+        &s.fleet,
+        &vec![
+            &s.env,
+            TimeWindow {
+                days: WEEKDAYS,
+                start_minute: 5 * 60,
+                end_minute: 22 * 60,
+            },
+        ],
+    );
+
+    // 03:00 UTC is 06:00 in Nairobi
+    assert_eq!(redeem_at(&s, MONDAY_UTC + 3 * HOUR), Ok(()));
+    // 20:00 UTC is 23:00 in Nairobi
+    assert_eq!(
+        redeem_at(&s, MONDAY_UTC + 20 * HOUR),
+        Err(RedemptionError::OutsideRedemptionWindow)
+    );
+}
+
+#[test]
+fn test_driver_windows_override_fleet_default() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption.set_fleet_time_windows(
+        &s.fleet,
+        &vec![
+            &s.env,
+            TimeWindow {
+                days: WEEKDAYS,
+                start_minute: 5 * 60,
+                end_minute: 22 * 60,
+            },
+        ],
+    );
+    // A night-shift driver, 20:00 to 06:00 starting on weekdays
+    let mut limits = default_limits(&s.env);
+    limits.allowed_windows = vec![
+        &s.env,
+        TimeWindow {
+            days: WEEKDAYS,
+            start_minute: 20 * 60,
+            end_minute: 6 * 60,
+        },
+    ];
+    s.redemption
+        .update_spending_limits(&s.fleet, &s.driver, &limits);
+
+    assert_eq!(
+        redeem_at(&s, MONDAY_UTC + 12 * HOUR),
+        Err(RedemptionError::OutsideRedemptionWindow)
+    );
+    assert_eq!(redeem_at(&s, MONDAY_UTC + 23 * HOUR), Ok(()));
+    // Tuesday 02:00 belongs to Monday night's window
+    assert_eq!(redeem_at(&s, MONDAY_UTC + 26 * HOUR), Ok(()));
+    // Monday 02:00 would belong to Sunday night, which is not allowed
+    assert_eq!(
+        redeem_at(&s, MONDAY_UTC + 2 * HOUR),
+        Err(RedemptionError::OutsideRedemptionWindow)
+    );
+}
+
+#[test]
+fn test_all_day_driver_window_lifts_fleet_default() {
+    let s = setup();
+    s.token.mint(&s.driver, &(1_000 * FUEL));
+    s.redemption.set_fleet_time_windows(
+        &s.fleet,
+        &vec![
+            &s.env,
+            TimeWindow {
+                days: WEEKDAYS,
+                start_minute: 5 * 60,
+                end_minute: 22 * 60,
+            },
+        ],
+    );
+    let mut limits = default_limits(&s.env);
+    limits.allowed_windows = vec![
+        &s.env,
+        TimeWindow {
+            days: 0b111_1111,
+            start_minute: 0,
+            end_minute: 24 * 60,
+        },
+    ];
+    s.redemption
+        .update_spending_limits(&s.fleet, &s.driver, &limits);
+
+    assert_eq!(redeem_at(&s, MONDAY_UTC + 2 * HOUR), Ok(()));
+    // Monday 23:59
+    assert_eq!(redeem_at(&s, MONDAY_UTC + 24 * HOUR - 60), Ok(()));
+    // Sunday midday
+    assert_eq!(redeem_at(&s, MONDAY_UTC + 6 * 24 * HOUR + 12 * HOUR), Ok(()));
+}
+
+#[test]
+fn test_invalid_time_window_rejected() {
+    let s = setup();
+    assert_eq!(
+        s.redemption.try_set_fleet_time_windows(
+            &s.fleet,
+            &vec![
+                &s.env,
+                TimeWindow {
+                    days: WEEKDAYS,
+                    start_minute: 5 * 60,
+                    end_minute: 24 * 60 + 1,
+                },
+            ],
+        ),
+        Err(Ok(RedemptionError::InvalidTimeWindow))
+    );
+}